@@ -0,0 +1,399 @@
+use solution::{Answer, InputKind, Part};
+
+pub const USAGE: &str = "Usage: aoc run <day|all> [--part <1|2>] [--input <path> | --example]
+                 [--format <text|json>] [--jobs <n>]
+       aoc bench <day|all> [run options] [--runs <n>] [--warmup <n>]
+                 [--save <path>] [--baseline <path>] [--threshold <percent>]
+       aoc record <day> [--part <1|2>] [--example] [--answer <answer>]
+       aoc new <day> [--title <title>]
+
+Commands:
+    run     Solve the given day, or every registered day with `all`, and check
+            the answers against the day's answers.toml
+    bench   Time reading the input and solving each part
+    record  Save the current answers of a day, or the given one, as confirmed
+    new     Create the crate of a new day and register it
+    help    Print this message
+
+Options:
+    --part <1|2>     Solve only the given part
+    --input <path>   Read the input from the given file instead of the day's input.txt
+    --example        Read the day's input_example.txt
+    --format <text|json>
+                     Print the answers as text, or as JSON with timings and verdicts
+    --jobs <n>       Solve up to n days at the same time, 1 by default. Running all
+                     days ends with a summary table
+
+Bench options:
+    --runs <n>       Timed repetitions of every step, 10 by default
+    --warmup <n>     Untimed repetitions before timing, 3 by default
+    --save <path>    Save the timings as a JSON baseline
+    --baseline <path>
+                     Compare the timings with a saved baseline
+    --threshold <percent>
+                     Slowdown of the median reported as a regression, 10 by default
+
+Record options:
+    --answer <answer>
+                     Record the given answer instead of solving, requires --part
+
+New options:
+    --title <title>  Title of the puzzle";
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum Command {
+    Run(RunArgs),
+    Bench(BenchArgs),
+    Record(RecordArgs),
+    New(NewArgs),
+    Help,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RunArgs {
+    pub days: Selection,
+    pub parts: Vec<Part>,
+    pub input: Input,
+    pub format: Format,
+    pub jobs: usize,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct BenchArgs {
+    pub run: RunArgs,
+    pub runs: usize,
+    pub warmup: usize,
+    pub save: Option<String>,
+    pub baseline: Option<String>,
+    /// Allowed slowdown of the median in percent.
+    pub threshold: f64,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RecordArgs {
+    pub day: u8,
+    pub parts: Vec<Part>,
+    pub kind: InputKind,
+    /// Answer given on the command line, otherwise the part is solved.
+    pub answer: Option<Answer>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct NewArgs {
+    pub day: u8,
+    pub title: String,
+}
+
+/// Days chosen on the command line.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Selection {
+    All,
+    Day(u8),
+}
+
+/// How `run` prints the answers.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Format {
+    Text,
+    Json,
+}
+
+/// Input chosen on the command line.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Input {
+    /// The day's own `input.txt`.
+    Real,
+    /// The day's own `input_example.txt`.
+    Example,
+    /// A file given with `--input`.
+    Path(String),
+}
+
+/// Parse the command line arguments, without the program name.
+pub fn parse(args: impl IntoIterator<Item = String>) -> Result<Command, String> {
+    let mut args = args.into_iter();
+
+    match args.next().as_deref() {
+        Some("run") => parse_run(args).map(Command::Run),
+        Some("bench") => parse_bench(args).map(Command::Bench),
+        Some("record") => parse_record(args).map(Command::Record),
+        Some("new") => parse_new(args).map(Command::New),
+        Some("help" | "--help" | "-h") | None => Ok(Command::Help),
+        Some(other) => Err(format!("Unknown command \"{}\".", other)),
+    }
+}
+
+fn parse_run(mut args: impl Iterator<Item = String>) -> Result<RunArgs, String> {
+    let mut days = None;
+    let mut parts = Part::BOTH.to_vec();
+    let mut input = Input::Real;
+    let mut format = Format::Text;
+    let mut jobs = 1;
+
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--part" => parts = vec![part(&mut args)?],
+            "--input" => input = Input::Path(value(&mut args, "--input")?),
+            "--format" => {
+                format = match value(&mut args, "--format")?.as_str() {
+                    "text" => Format::Text,
+                    "json" => Format::Json,
+                    other => {
+                        return Err(format!("Unknown format \"{}\", use text or json.", other))
+                    }
+                }
+            }
+            "--example" => input = Input::Example,
+            "--jobs" => jobs = number(&mut args, "--jobs")?,
+            "all" if days.is_none() => days = Some(Selection::All),
+            day if days.is_none() && !day.starts_with('-') => {
+                let day = day
+                    .parse::<u8>()
+                    .map_err(|_| format!("Invalid day \"{}\", use a number or all.", day))?;
+                days = Some(Selection::Day(day));
+            }
+            other => return Err(format!("Unexpected argument \"{}\".", other)),
+        }
+    }
+
+    let days = days.ok_or("Missing the day to run.")?;
+    if days == Selection::All && matches!(input, Input::Path(_)) {
+        return Err("--input can only be used with a single day.".to_string());
+    }
+    if jobs == 0 {
+        return Err("--jobs must be at least 1.".to_string());
+    }
+
+    Ok(RunArgs {
+        days,
+        parts,
+        input,
+        format,
+        jobs,
+    })
+}
+
+fn parse_bench(mut args: impl Iterator<Item = String>) -> Result<BenchArgs, String> {
+    let mut run = Vec::new();
+    let mut runs = 10;
+    let mut warmup = 3;
+    let mut save = None;
+    let mut baseline = None;
+    let mut threshold = 10.0;
+
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--runs" => runs = number(&mut args, "--runs")?,
+            "--warmup" => warmup = number(&mut args, "--warmup")?,
+            "--save" => save = Some(value(&mut args, "--save")?),
+            "--baseline" => baseline = Some(value(&mut args, "--baseline")?),
+            "--threshold" => threshold = number(&mut args, "--threshold")?,
+            _ => run.push(arg),
+        }
+    }
+
+    if runs == 0 {
+        return Err("--runs must be at least 1.".to_string());
+    }
+    if let Some(arg) = run
+        .iter()
+        .find(|arg| *arg == "--format" || *arg == "--jobs")
+    {
+        return Err(format!("{} can only be used with run.", arg));
+    }
+    if threshold < 0.0 {
+        return Err("--threshold can not be negative.".to_string());
+    }
+
+    Ok(BenchArgs {
+        run: parse_run(run.into_iter())?,
+        runs,
+        warmup,
+        save,
+        baseline,
+        threshold,
+    })
+}
+
+fn parse_record(mut args: impl Iterator<Item = String>) -> Result<RecordArgs, String> {
+    let mut day = None;
+    let mut parts = Part::BOTH.to_vec();
+    let mut kind = InputKind::Real;
+    let mut answer = None;
+
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--part" => parts = vec![part(&mut args)?],
+            "--example" => kind = InputKind::Example,
+            "--answer" => answer = Some(value(&mut args, "--answer")?.parse().unwrap()),
+            other if day.is_none() && !other.starts_with('-') => day = Some(day_number(other)?),
+            other => return Err(format!("Unexpected argument \"{}\".", other)),
+        }
+    }
+
+    let day = day.ok_or("Missing the day to record.")?;
+    if answer.is_some() && parts.len() != 1 {
+        return Err("--answer needs the part given with --part.".to_string());
+    }
+
+    Ok(RecordArgs {
+        day,
+        parts,
+        kind,
+        answer,
+    })
+}
+
+fn parse_new(mut args: impl Iterator<Item = String>) -> Result<NewArgs, String> {
+    let mut day = None;
+    let mut title = String::new();
+
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--title" => title = value(&mut args, "--title")?,
+            other if day.is_none() && !other.starts_with('-') => day = Some(day_number(other)?),
+            other => return Err(format!("Unexpected argument \"{}\".", other)),
+        }
+    }
+
+    let day = day.ok_or("Missing the day to create.")?;
+    if !(1..=25).contains(&day) {
+        return Err(format!("Day {} is not in the calendar, use 1 to 25.", day));
+    }
+
+    Ok(NewArgs { day, title })
+}
+
+fn day_number(arg: &str) -> Result<u8, String> {
+    arg.parse()
+        .map_err(|_| format!("Invalid day \"{}\", use a number.", arg))
+}
+
+fn part(args: &mut impl Iterator<Item = String>) -> Result<Part, String> {
+    match value(args, "--part")?.as_str() {
+        "1" => Ok(Part::One),
+        "2" => Ok(Part::Two),
+        other => Err(format!("Unknown part \"{}\", use 1 or 2.", other)),
+    }
+}
+
+fn number<T: std::str::FromStr>(
+    args: &mut impl Iterator<Item = String>,
+    option: &str,
+) -> Result<T, String> {
+    let value = value(args, option)?;
+    value
+        .parse()
+        .map_err(|_| format!("Invalid number \"{}\" for {}.", value, option))
+}
+
+fn value(args: &mut impl Iterator<Item = String>, option: &str) -> Result<String, String> {
+    args.next()
+        .ok_or_else(|| format!("Missing a value for {}.", option))
+}
+
+#[cfg(test)]
+fn parse_str(args: &str) -> Result<Command, String> {
+    parse(args.split_whitespace().map(|arg| arg.to_string()))
+}
+
+#[test]
+fn test_parse_run_day() {
+    assert_eq!(
+        parse_str("run 12 --part 2 --example"),
+        Ok(Command::Run(RunArgs {
+            days: Selection::Day(12),
+            parts: vec![Part::Two],
+            input: Input::Example,
+            format: Format::Text,
+            jobs: 1,
+        }))
+    );
+}
+
+#[test]
+fn test_parse_run_all_with_input() {
+    assert!(parse_str("run all --input other.txt").is_err());
+}
+
+#[test]
+fn test_parse_bench() {
+    assert_eq!(
+        parse_str("bench 15 --runs 5 --part 1 --baseline base.json --threshold 2.5"),
+        Ok(Command::Bench(BenchArgs {
+            run: RunArgs {
+                days: Selection::Day(15),
+                parts: vec![Part::One],
+                input: Input::Real,
+                format: Format::Text,
+                jobs: 1,
+            },
+            runs: 5,
+            warmup: 3,
+            save: None,
+            baseline: Some("base.json".to_string()),
+            threshold: 2.5,
+        }))
+    );
+    assert!(parse_str("bench all --runs 0").is_err());
+    assert!(parse_str("bench all --warmup many").is_err());
+}
+
+#[test]
+fn test_parse_record() {
+    assert_eq!(
+        parse_str("record 5 --example --part 2 --answer MCD"),
+        Ok(Command::Record(RecordArgs {
+            day: 5,
+            parts: vec![Part::Two],
+            kind: InputKind::Example,
+            answer: Some(Answer::Text("MCD".to_string())),
+        }))
+    );
+    assert!(parse_str("record all").is_err());
+    assert!(parse_str("record 1 --answer 24000").is_err());
+}
+
+#[test]
+fn test_parse_new() {
+    assert_eq!(
+        parse(["new", "17", "--title", "Pyroclastic Flow"].map(String::from)),
+        Ok(Command::New(NewArgs {
+            day: 17,
+            title: "Pyroclastic Flow".to_string(),
+        }))
+    );
+    assert!(parse_str("new 26").is_err());
+    assert!(parse_str("new").is_err());
+}
+
+#[test]
+fn test_parse_format() {
+    assert!(matches!(
+        parse_str("run all --format json"),
+        Ok(Command::Run(RunArgs {
+            format: Format::Json,
+            ..
+        }))
+    ));
+    assert!(parse_str("run all --format yaml").is_err());
+    assert!(parse_str("bench all --format json").is_err());
+}
+
+#[test]
+fn test_parse_jobs() {
+    assert!(matches!(
+        parse_str("run all --jobs 4"),
+        Ok(Command::Run(RunArgs { jobs: 4, .. }))
+    ));
+    assert!(parse_str("run all --jobs 0").is_err());
+    assert!(parse_str("bench all --jobs 4").is_err());
+}
+
+#[test]
+fn test_parse_invalid() {
+    assert!(parse_str("run").is_err());
+    assert!(parse_str("run 3 4").is_err());
+    assert!(parse_str("run 3 --part 3").is_err());
+    assert!(parse_str("walk 3").is_err());
+}
@@ -0,0 +1,152 @@
+use crate::solve::panic_message;
+use serde::{Deserialize, Serialize};
+use std::{
+    collections::BTreeMap,
+    fmt, fs,
+    hint::black_box,
+    panic::{self, AssertUnwindSafe},
+    time::{Duration, Instant},
+};
+
+/// Timings of one step over every timed run, in nanoseconds.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Stats {
+    pub min_ns: u64,
+    pub median_ns: u64,
+    pub p95_ns: u64,
+}
+
+impl Stats {
+    fn from_samples(mut samples: Vec<Duration>) -> Stats {
+        samples.sort();
+        Stats {
+            min_ns: nanos(samples[0]),
+            median_ns: nanos(percentile(&samples, 50.0)),
+            p95_ns: nanos(percentile(&samples, 95.0)),
+        }
+    }
+
+    /// Change of the median compared to an earlier run, in percent.
+    pub fn change(&self, earlier: &Stats) -> f64 {
+        (self.median_ns as f64 / earlier.median_ns.max(1) as f64 - 1.0) * 100.0
+    }
+}
+
+impl fmt::Display for Stats {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "min {:>10.2?}  median {:>10.2?}  p95 {:>10.2?}",
+            Duration::from_nanos(self.min_ns),
+            Duration::from_nanos(self.median_ns),
+            Duration::from_nanos(self.p95_ns)
+        )
+    }
+}
+
+/// Run `step` `warmup` times untimed and then `runs` times timed. `setup`
+/// prepares the argument of every run outside of the timing.
+///
+/// Returns the message of the panic if `setup` or `step` panics.
+pub fn measure<I, T>(
+    warmup: usize,
+    runs: usize,
+    mut setup: impl FnMut() -> I,
+    mut step: impl FnMut(I) -> T,
+) -> Result<Stats, String> {
+    panic::catch_unwind(AssertUnwindSafe(|| {
+        for _ in 0..warmup {
+            black_box(step(setup()));
+        }
+
+        let samples = (0..runs.max(1))
+            .map(|_| {
+                let input = setup();
+                let start = Instant::now();
+                black_box(step(input));
+                start.elapsed()
+            })
+            .collect();
+
+        Stats::from_samples(samples)
+    }))
+    .map_err(panic_message)
+}
+
+/// Nearest-rank percentile of sorted samples.
+fn percentile(sorted: &[Duration], percent: f64) -> Duration {
+    let rank = (percent / 100.0 * sorted.len() as f64).ceil() as usize;
+    sorted[rank.clamp(1, sorted.len()) - 1]
+}
+
+fn nanos(duration: Duration) -> u64 {
+    duration.as_nanos().try_into().unwrap_or(u64::MAX)
+}
+
+/// Saved timings of every benchmarked day, keyed by day and step.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Baseline {
+    /// Input the timings were measured with: `real`, `example` or a path.
+    pub input: String,
+    pub days: BTreeMap<u8, BTreeMap<String, Stats>>,
+}
+
+impl Baseline {
+    pub fn new(input: String) -> Baseline {
+        Baseline {
+            input,
+            days: BTreeMap::new(),
+        }
+    }
+
+    pub fn load(path: &str) -> Result<Baseline, String> {
+        let text = fs::read_to_string(path)
+            .map_err(|err| format!("Unable to read baseline {}: {}", path, err))?;
+        serde_json::from_str(&text).map_err(|err| format!("Invalid baseline {}: {}", path, err))
+    }
+
+    pub fn save(&self, path: &str) -> Result<(), String> {
+        let json = serde_json::to_string_pretty(self).expect("Baseline is valid JSON");
+        fs::write(path, json + "\n")
+            .map_err(|err| format!("Unable to write baseline {}: {}", path, err))
+    }
+
+    pub fn get(&self, day: u8, step: &str) -> Option<&Stats> {
+        self.days.get(&day)?.get(step)
+    }
+
+    pub fn insert(&mut self, day: u8, step: &str, stats: Stats) {
+        self.days
+            .entry(day)
+            .or_default()
+            .insert(step.to_string(), stats);
+    }
+}
+
+#[test]
+fn test_percentile() {
+    let samples: Vec<Duration> = (1..=20).map(Duration::from_millis).collect();
+    assert_eq!(percentile(&samples, 50.0), Duration::from_millis(10));
+    assert_eq!(percentile(&samples, 95.0), Duration::from_millis(19));
+    assert_eq!(percentile(&samples[..1], 95.0), Duration::from_millis(1));
+}
+
+#[test]
+fn test_measure_panic() {
+    let stats = measure(1, 3, || 1, |value| value + 1).unwrap();
+    assert!(stats.min_ns <= stats.median_ns && stats.median_ns <= stats.p95_ns);
+
+    let err = measure(0, 3, || (), |_| -> () { panic!("step failed") });
+    assert_eq!(err, Err("step failed".to_string()));
+}
+
+#[test]
+fn test_change() {
+    let stats = |median_ns| Stats {
+        min_ns: 0,
+        median_ns,
+        p95_ns: 0,
+    };
+    assert_eq!(stats(150).change(&stats(100)), 50.0);
+    assert_eq!(stats(50).change(&stats(100)), -50.0);
+}
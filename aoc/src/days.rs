@@ -0,0 +1,23 @@
+use solution::Day;
+
+/// Every registered day, in order.
+pub fn all() -> Vec<Day> {
+    vec![
+        Day::of::<day_1::Day1>(),
+        Day::of::<day_2::Day2>(),
+        Day::of::<day_3::Day3>(),
+        Day::of::<day_4::Day4>(),
+        Day::of::<day_5::Day5>(),
+        Day::of::<day_6::Day6>(),
+        Day::of::<day_7::Day7>(),
+        Day::of::<day_8::Day8>(),
+        Day::of::<day_9::Day9>(),
+        Day::of::<day_10::Day10>(),
+        Day::of::<day_11::Day11>(),
+        Day::of::<day_12::Day12>(),
+        Day::of::<day_13::Day13>(),
+        Day::of::<day_14::Day14>(),
+        Day::of::<day_15::Day15>(),
+        Day::of::<day_16::Day16>(),
+    ]
+}
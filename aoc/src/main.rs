@@ -0,0 +1,238 @@
+mod args;
+mod bench;
+mod days;
+mod output;
+mod scaffold;
+mod solve;
+
+use args::{BenchArgs, Command, Format, Input, NewArgs, RecordArgs, RunArgs, Selection, USAGE};
+use bench::Baseline;
+use solution::{Day, InputKind};
+use std::{path::Path, process};
+
+fn main() {
+    let command = args::parse(std::env::args().skip(1)).unwrap_or_else(|err| {
+        eprintln!("{}\n\n{}", err, USAGE);
+        process::exit(2);
+    });
+
+    match command {
+        Command::Help => println!("{}", USAGE),
+        Command::Run(args) => {
+            if !run(&args) {
+                process::exit(1);
+            }
+        }
+        Command::Bench(args) => {
+            if !bench(&args) {
+                process::exit(1);
+            }
+        }
+        Command::Record(args) => {
+            if let Err(err) = record(&args) {
+                eprintln!("{}", err);
+                process::exit(1);
+            }
+        }
+        Command::New(args) => {
+            if let Err(err) = new(&args) {
+                eprintln!("{}", err);
+                process::exit(1);
+            }
+        }
+    }
+}
+
+/// Solve the selected days, returning whether every input could be read and
+/// every part was solved without a panic or an answer differing from the
+/// confirmed one.
+fn run(args: &RunArgs) -> bool {
+    let mut results = Vec::new();
+
+    solve::solve_all(&selected(args.days), args, args.jobs, |result| {
+        if args.format == Format::Text {
+            output::print_day(&result);
+        }
+        results.push(result);
+    });
+
+    match args.format {
+        Format::Text if args.days == Selection::All => output::print_summary(&results),
+        Format::Text => {}
+        Format::Json => println!(
+            "{}",
+            serde_json::to_string_pretty(&output::json(&results)).unwrap()
+        ),
+    }
+
+    results.iter().all(|result| result.passed())
+}
+
+/// Save answers of a day as confirmed in its answers.toml.
+fn record(args: &RecordArgs) -> Result<(), String> {
+    let day = selected(Selection::Day(args.day)).remove(0);
+    let mut answers = day.answers()?;
+
+    for &part in args.parts.iter() {
+        let answer = match &args.answer {
+            Some(answer) => answer.clone(),
+            None => {
+                let input =
+                    reader::try_open(&path(&day, args.kind)).map_err(|err| err.to_string())?;
+                day.solve(part, input, args.kind)
+            }
+        };
+
+        match answers.get(args.kind, part) {
+            Some(earlier) if *earlier == answer => {}
+            Some(earlier) => println!(
+                "Day {} part {}: replacing {} with {} for the {} input",
+                day.day, part, earlier, answer, args.kind
+            ),
+            None => println!(
+                "Day {} part {}: recording {} for the {} input",
+                day.day, part, answer, args.kind
+            ),
+        }
+        answers.set(args.kind, part, answer);
+    }
+
+    answers.save(Path::new(day.dir))
+}
+
+/// Create and register the crate of a new day in the repository.
+fn new(args: &NewArgs) -> Result<(), String> {
+    let root = Path::new(env!("CARGO_MANIFEST_DIR"))
+        .parent()
+        .expect("The runner is inside the repository");
+    scaffold::new_day(root, args.day, &args.title)?;
+
+    println!(
+        "Created day-{0}. Add the example to day-{0}/input_example.txt and your input to \
+         day-{0}/input.txt, solve it in day-{0}/src/lib.rs and run it with `aoc run {0}`.",
+        args.day
+    );
+    Ok(())
+}
+
+/// Time the selected days, returning whether every input could be read and
+/// no step got slower than the baseline allows.
+fn bench(args: &BenchArgs) -> bool {
+    let label = match &args.run.input {
+        Input::Real => "real".to_string(),
+        Input::Example => "example".to_string(),
+        Input::Path(path) => path.clone(),
+    };
+
+    let baseline = match args.baseline.as_deref().map(Baseline::load).transpose() {
+        Ok(Some(baseline)) if baseline.input != label => {
+            eprintln!(
+                "Baseline was measured with the {} input, not {}.",
+                baseline.input, label
+            );
+            return false;
+        }
+        Ok(baseline) => baseline,
+        Err(err) => {
+            eprintln!("{}", err);
+            return false;
+        }
+    };
+
+    let mut results = Baseline::new(label);
+    let mut ok = true;
+
+    for day in selected(args.run.days) {
+        let (path, kind) = input_path(&day, &args.run.input);
+        let input = match reader::try_open(&path) {
+            Ok(input) => input,
+            Err(err) => {
+                eprintln!("Day {}: {}", day.day, err);
+                ok = false;
+                continue;
+            }
+        };
+
+        println!("Day {}: {}", day.day, day.title);
+        let mut steps = vec![(
+            "parse".to_string(),
+            bench::measure(
+                args.warmup,
+                args.runs,
+                || input.clone(),
+                |input| day.parse(input),
+            ),
+        )];
+        // The parts are timed on the parsed input, which is not there if parsing panicked.
+        if steps[0].1.is_ok() {
+            let parsed = day.parse(input);
+            for &part in args.run.parts.iter() {
+                let stats = bench::measure(
+                    args.warmup,
+                    args.runs,
+                    || (),
+                    |_| day.solve_parsed(part, &parsed, kind),
+                );
+                steps.push((format!("part {}", part), stats));
+            }
+        }
+
+        for (step, stats) in steps {
+            let stats = match stats {
+                Ok(stats) => stats,
+                Err(message) => {
+                    println!("\t{:<8}panicked: {}", step, message);
+                    ok = false;
+                    continue;
+                }
+            };
+            let earlier = baseline.as_ref().and_then(|b| b.get(day.day, &step));
+            match earlier.map(|earlier| stats.change(earlier)) {
+                Some(change) if change > args.threshold => {
+                    println!("\t{:<8}{}  {:+.1}% REGRESSION", step, stats, change);
+                    ok = false;
+                }
+                Some(change) => println!("\t{:<8}{}  {:+.1}%", step, stats, change),
+                None => println!("\t{:<8}{}", step, stats),
+            }
+            results.insert(day.day, &step, stats);
+        }
+    }
+
+    if let Some(path) = &args.save {
+        if let Err(err) = results.save(path) {
+            eprintln!("{}", err);
+            ok = false;
+        }
+    }
+
+    ok
+}
+
+fn selected(selection: Selection) -> Vec<Day> {
+    let days = days::all();
+
+    match selection {
+        Selection::All => days,
+        Selection::Day(number) => match days.into_iter().find(|day| day.day == number) {
+            Some(day) => vec![day],
+            None => {
+                eprintln!("Day {} is not registered.", number);
+                process::exit(2);
+            }
+        },
+    }
+}
+
+/// Path of the chosen input, and which kind of input it is.
+fn input_path(day: &Day, input: &Input) -> (String, InputKind) {
+    match input {
+        Input::Real => (path(day, InputKind::Real), InputKind::Real),
+        Input::Example => (path(day, InputKind::Example), InputKind::Example),
+        Input::Path(path) => (path.clone(), InputKind::Real),
+    }
+}
+
+fn path(day: &Day, kind: InputKind) -> String {
+    day.input_path(kind).to_string_lossy().into_owned()
+}
@@ -0,0 +1,244 @@
+use crate::solve::{DayResult, Outcome};
+use serde_json::{json, Value};
+use solution::{Answer, Part, Verdict};
+use std::time::Duration;
+
+/// Answer to one part with how long solving it took.
+pub struct Solved {
+    pub part: Part,
+    /// The message of the panic if solving panicked.
+    pub answer: Result<Answer, String>,
+    pub time: Duration,
+    /// Left out for inputs that have no confirmed answers, and for panics.
+    pub verdict: Option<Verdict>,
+}
+
+impl Solved {
+    /// Whether the part was solved without a panic and no confirmed answer differs.
+    pub fn passed(&self) -> bool {
+        self.answer.is_ok() && !matches!(self.verdict, Some(Verdict::Wrong(_)))
+    }
+
+    fn status(&self) -> &'static str {
+        match (&self.answer, &self.verdict) {
+            (Err(_), _) => "panicked",
+            (_, Some(Verdict::Correct)) => "correct",
+            (_, Some(Verdict::Wrong(_))) => "wrong",
+            (_, Some(Verdict::Unknown) | None) => "unknown",
+        }
+    }
+}
+
+/// Print the answers of a day below its title.
+pub fn print_day(result: &DayResult) {
+    let solved = match &result.outcome {
+        Outcome::Solved(solved) => solved,
+        Outcome::Skipped(reason) => {
+            println!("Day {}: skipped, {}", result.day.day, reason);
+            return;
+        }
+        Outcome::Failed(err) => {
+            eprintln!("Day {}: {}", result.day.day, err);
+            return;
+        }
+    };
+
+    println!("Day {}: {}", result.day.day, result.day.title);
+    for solved in solved {
+        match &solved.answer {
+            Ok(answer) => solution::print_answer(solved.part, answer, solved.verdict.as_ref()),
+            Err(message) => println!("\tPart {}: panicked: {}", solved.part, message),
+        }
+    }
+}
+
+/// Print a table of every part with its answer, timing and status, followed by
+/// the totals.
+pub fn print_summary(results: &[DayResult]) {
+    println!();
+    print!("{}", summary(results));
+}
+
+/// Table of `print_summary`.
+pub fn summary(results: &[DayResult]) -> String {
+    let mut rows = vec![[
+        "Day".to_string(),
+        "Part".to_string(),
+        "Answer".to_string(),
+        "Time".to_string(),
+        "Status".to_string(),
+    ]];
+    let mut counts = [
+        ("correct", 0),
+        ("wrong", 0),
+        ("unknown", 0),
+        ("panicked", 0),
+    ];
+    let mut skipped = 0;
+    let mut unreadable = 0;
+
+    for result in results {
+        let day = result.day.day.to_string();
+        match &result.outcome {
+            Outcome::Solved(solved) => {
+                for solved in solved {
+                    let answer = match &solved.answer {
+                        Ok(Answer::Grid(rows)) => {
+                            let width = rows.iter().map(|row| row.len()).max().unwrap_or(0);
+                            format!("grid {}x{}", width, rows.len())
+                        }
+                        Ok(answer) => answer.to_string(),
+                        Err(_) => "-".to_string(),
+                    };
+                    let status = solved.status();
+                    for (name, count) in counts.iter_mut() {
+                        if *name == status {
+                            *count += 1;
+                        }
+                    }
+                    rows.push([
+                        day.clone(),
+                        solved.part.to_string(),
+                        answer,
+                        format!("{:.2?}", solved.time),
+                        status.to_string(),
+                    ]);
+                }
+            }
+            Outcome::Skipped(_) => {
+                skipped += 1;
+                rows.push([
+                    day,
+                    "-".to_string(),
+                    "-".to_string(),
+                    "-".to_string(),
+                    "skipped".to_string(),
+                ]);
+            }
+            Outcome::Failed(err) => {
+                unreadable += 1;
+                let reason = err.lines().next().unwrap_or_default();
+                rows.push([
+                    day,
+                    "-".to_string(),
+                    "-".to_string(),
+                    "-".to_string(),
+                    format!("error: {}", reason),
+                ]);
+            }
+        }
+    }
+
+    let mut widths = [0; 5];
+    for row in rows.iter() {
+        for (width, cell) in widths.iter_mut().zip(row) {
+            *width = (*width).max(cell.chars().count());
+        }
+    }
+
+    let mut summary = String::new();
+    for row in rows.iter() {
+        summary += &format!(
+            "{:>w0$}  {:>w1$}  {:<w2$}  {:>w3$}  {}\n",
+            row[0],
+            row[1],
+            row[2],
+            row[3],
+            row[4],
+            w0 = widths[0],
+            w1 = widths[1],
+            w2 = widths[2],
+            w3 = widths[3],
+        );
+    }
+
+    let totals: Vec<String> = counts
+        .iter()
+        .map(|(name, count)| format!("{} {}", count, name))
+        .collect();
+    summary += &format!(
+        "\n{}, {} days skipped, {} days unreadable\n",
+        totals.join(", "),
+        skipped,
+        unreadable
+    );
+    summary
+}
+
+/// Every day as JSON.
+pub fn json(results: &[DayResult]) -> Value {
+    json!({ "days": results.iter().map(day).collect::<Vec<_>>() })
+}
+
+/// A solved day as JSON.
+/// ## Example
+/// ```json
+/// {
+///   "day": 5,
+///   "title": "Supply Stacks",
+///   "input": "day-5/input.txt",
+///   "parts": [
+///     {
+///       "part": 1,
+///       "answer": { "type": "string", "value": "ZWHVFWQWW" },
+///       "time_ns": 41250,
+///       "status": "correct"
+///     }
+///   ]
+/// }
+/// ```
+/// Days whose input or answers could not be read have an `error` instead of
+/// `parts`, and days whose input is missing or empty have `skipped`.
+fn day(result: &DayResult) -> Value {
+    let mut day = json!({
+        "day": result.day.day,
+        "title": result.day.title,
+        "input": result.input,
+    });
+    match &result.outcome {
+        Outcome::Solved(solved) => day["parts"] = solved.iter().map(part).collect(),
+        Outcome::Skipped(reason) => day["skipped"] = json!(reason),
+        Outcome::Failed(err) => day["error"] = json!(err),
+    }
+    day
+}
+
+fn part(solved: &Solved) -> Value {
+    let mut part = json!({
+        "part": match solved.part {
+            Part::One => 1,
+            Part::Two => 2,
+        },
+        "time_ns": solved.time.as_nanos() as u64,
+        "status": solved.status(),
+    });
+    match &solved.answer {
+        Ok(solved) => part["answer"] = answer(solved),
+        Err(message) => part["error"] = json!(message),
+    }
+    if let Some(Verdict::Wrong(expected)) = &solved.verdict {
+        part["expected"] = answer(expected);
+    }
+    part
+}
+
+fn answer(answer: &Answer) -> Value {
+    match answer {
+        Answer::Number(number) => json!({ "type": "number", "value": number }),
+        Answer::Text(text) => json!({ "type": "string", "value": text }),
+        Answer::Grid(rows) => json!({ "type": "grid", "value": rows }),
+    }
+}
+
+#[test]
+fn test_answer_types() {
+    assert_eq!(answer(&42.into()), json!({ "type": "number", "value": 42 }));
+    assert_eq!(
+        answer(&"CMZ".into()),
+        json!({ "type": "string", "value": "CMZ" })
+    );
+    assert_eq!(
+        answer(&"#.\n.#".into()),
+        json!({ "type": "grid", "value": ["#.", ".#"] })
+    );
+}
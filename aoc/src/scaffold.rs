@@ -0,0 +1,242 @@
+use std::{fs, path::Path};
+
+/// Version of the templates in `aoc/templates`, written to the generated
+/// `Cargo.toml`. Bump it whenever the templates change.
+pub const TEMPLATE_VERSION: u32 = 3;
+
+const CARGO_TOML: &str = include_str!("../templates/Cargo.toml.template");
+const LIB_RS: &str = include_str!("../templates/lib.rs.template");
+const MAIN_RS: &str = include_str!("../templates/main.rs.template");
+const ANSWERS_TOML: &str = include_str!("../templates/answers.toml.template");
+const BUILD_RS: &str = include_str!("../templates/build.rs.template");
+
+/// Create the crate of a new day from the templates with empty inputs, and
+/// register it in the workspace and the runner. Nothing is written if the day
+/// already exists.
+pub fn new_day(root: &Path, day: u8, title: &str) -> Result<(), String> {
+    let dir = root.join(format!("day-{}", day));
+    if dir.exists() {
+        return Err(format!("{} already exists.", dir.display()));
+    }
+
+    // Prepare every registration first, so a failure leaves the files untouched.
+    let workspace = root.join("Cargo.toml");
+    let runner = root.join("aoc/Cargo.toml");
+    let days = root.join("aoc/src/days.rs");
+
+    let member_line = format!("    \"day-{}\",", day);
+    let path_line = format!("day-{0} = {{ path = \"day-{0}\" }}", day);
+    let runner_line = format!("day-{} = {{ workspace = true }}", day);
+    let days_line = format!("        Day::of::<day_{0}::Day{0}>(),", day);
+
+    let workspace_text = register(&read(&workspace)?, day, &member_line, member)?;
+    let workspace_text = register(&workspace_text, day, &path_line, dependency)?;
+    let runner_text = register(&read(&runner)?, day, &runner_line, dependency)?;
+    let days_text = register(&read(&days)?, day, &days_line, registered)?;
+
+    let files = [
+        ("Cargo.toml", CARGO_TOML),
+        ("src/lib.rs", LIB_RS),
+        ("src/main.rs", MAIN_RS),
+        ("answers.toml", ANSWERS_TOML),
+        ("build.rs", BUILD_RS),
+        ("input.txt", ""),
+        ("input_example.txt", ""),
+    ];
+
+    fs::create_dir_all(dir.join("src")).map_err(|err| err.to_string())?;
+    for (name, template) in files {
+        write(&dir.join(name), &render(template, day, title))?;
+    }
+    write(&workspace, &workspace_text)?;
+    write(&runner, &runner_text)?;
+    write(&days, &days_text)?;
+
+    Ok(())
+}
+
+/// Fill in the placeholders of a template.
+fn render(template: &str, day: u8, title: &str) -> String {
+    template
+        .replace("{day}", &day.to_string())
+        .replace("{title}", &title.escape_debug().to_string())
+        .replace("{version}", &TEMPLATE_VERSION.to_string())
+}
+
+/// Insert `line` after the line of the closest earlier day, keeping the days
+/// in order. `day_of` finds the day a line registers.
+fn register(
+    text: &str,
+    day: u8,
+    line: &str,
+    day_of: fn(&str) -> Option<u8>,
+) -> Result<String, String> {
+    let mut lines: Vec<&str> = text.lines().collect();
+    let registered: Vec<(usize, u8)> = lines
+        .iter()
+        .enumerate()
+        .filter_map(|(i, line)| Some((i, day_of(line)?)))
+        .collect();
+
+    if registered.iter().any(|&(_, other)| other == day) {
+        return Err(format!("Day {} is already registered.", day));
+    }
+
+    let index = match registered.iter().rfind(|&&(_, other)| other < day) {
+        Some(&(i, _)) => i + 1,
+        None => match registered.first() {
+            Some(&(i, _)) => i,
+            None => return Err(format!("Found no days to register day {} next to.", day)),
+        },
+    };
+
+    lines.insert(index, line);
+    Ok(lines.join("\n") + "\n")
+}
+
+/// `"day-1",` in the workspace members.
+fn member(line: &str) -> Option<u8> {
+    line.trim()
+        .strip_prefix("\"day-")?
+        .strip_suffix("\",")?
+        .parse()
+        .ok()
+}
+
+/// `day-1 = { ... }` in dependencies.
+fn dependency(line: &str) -> Option<u8> {
+    line.strip_prefix("day-")?.split_once(' ')?.0.parse().ok()
+}
+
+/// `Day::of::<day_1::Day1>(),` in the runner.
+fn registered(line: &str) -> Option<u8> {
+    line.trim()
+        .strip_prefix("Day::of::<day_")?
+        .split_once("::")?
+        .0
+        .parse()
+        .ok()
+}
+
+fn read(path: &Path) -> Result<String, String> {
+    fs::read_to_string(path).map_err(|err| format!("Unable to read {}: {}", path.display(), err))
+}
+
+fn write(path: &Path, text: &str) -> Result<(), String> {
+    fs::write(path, text).map_err(|err| format!("Unable to write {}: {}", path.display(), err))
+}
+
+#[test]
+fn test_register() {
+    let text = "[dependencies]\nday-1 = { workspace = true }\nday-3 = { workspace = true }\nreader = { workspace = true }\n";
+
+    assert_eq!(
+        register(text, 2, "day-2 = { workspace = true }", dependency),
+        Ok("[dependencies]\nday-1 = { workspace = true }\nday-2 = { workspace = true }\nday-3 = { workspace = true }\nreader = { workspace = true }\n".to_string())
+    );
+    assert!(register(text, 3, "day-3 = { workspace = true }", dependency).is_err());
+}
+
+#[test]
+fn test_render() {
+    let lib = render(LIB_RS, 17, "Pyroclastic \"Flow\"");
+    assert!(lib.contains("impl Solution for Day17 {"));
+    assert!(lib.contains(r#"const TITLE: &'static str = "Pyroclastic \"Flow\"";"#));
+    assert!(render(CARGO_TOML, 17, "").contains("name = \"day-17\""));
+}
+
+#[cfg(test)]
+struct Scaffolded;
+
+/// Stand-in for the solution generated from `LIB_RS`, which answers 0. The
+/// generated crate is not compiled by the tests.
+#[cfg(test)]
+impl solution::Solution for Scaffolded {
+    const DAY: u8 = 17;
+    const TITLE: &'static str = "Pyroclastic Flow";
+    const DIR: &'static str = "";
+
+    type Input = Vec<String>;
+    type PartOne = usize;
+    type PartTwo = usize;
+
+    fn parse(input: reader::Reader) -> Vec<String> {
+        input.lines()
+    }
+
+    fn part_one(_input: &Vec<String>) -> usize {
+        0
+    }
+
+    fn part_two(_input: &Vec<String>) -> usize {
+        0
+    }
+}
+
+#[test]
+fn test_new_day() {
+    use crate::{
+        args::{Format, Input, RunArgs, Selection},
+        solve::Outcome,
+    };
+    use solution::{Day, Part};
+
+    let root = std::env::temp_dir().join(format!("aoc-new-day-{}", std::process::id()));
+    fs::create_dir_all(root.join("aoc/src")).unwrap();
+    fs::write(
+        root.join("Cargo.toml"),
+        "[workspace]\nmembers = [\n    \"day-1\",\n]\n\n[workspace.dependencies]\nday-1 = { path = \"day-1\" }\n",
+    )
+    .unwrap();
+    fs::write(
+        root.join("aoc/Cargo.toml"),
+        "[dependencies]\nday-1 = { workspace = true }\n",
+    )
+    .unwrap();
+    fs::write(
+        root.join("aoc/src/days.rs"),
+        "    vec![\n        Day::of::<day_1::Day1>(),\n    ]\n",
+    )
+    .unwrap();
+
+    new_day(&root, 17, "Pyroclastic Flow").unwrap();
+    let dir = root.join("day-17");
+    assert!(read(&root.join("aoc/src/days.rs"))
+        .unwrap()
+        .contains("Day::of::<day_17::Day17>(),"));
+    assert_eq!(read(&dir.join("input.txt")), Ok(String::new()));
+    assert!(new_day(&root, 17, "Pyroclastic Flow").is_err());
+
+    // Run the stand-in on the inputs of the new day.
+    let mut day = Day::of::<Scaffolded>();
+    day.dir = Box::leak(dir.to_string_lossy().into_owned().into_boxed_str());
+    let args = RunArgs {
+        days: Selection::Day(17),
+        parts: Part::BOTH.to_vec(),
+        input: Input::Example,
+        format: Format::Text,
+        jobs: 1,
+    };
+    let run = || {
+        let mut results = vec![];
+        crate::solve::solve_all(&[day], &args, 1, |result| results.push(result));
+        results.pop().unwrap()
+    };
+
+    // Until the example is added the day is skipped.
+    let result = run();
+    assert!(result.passed());
+    assert!(
+        matches!(result.outcome, Outcome::Skipped(reason) if reason.ends_with("is missing or empty."))
+    );
+
+    fs::write(dir.join("input_example.txt"), "1\n2\n").unwrap();
+    let result = run();
+    assert!(result.passed());
+    match result.outcome {
+        Outcome::Solved(solved) => assert_eq!(solved[0].answer, Ok(0.into())),
+        _ => panic!("Day 17 was not solved"),
+    }
+
+    fs::remove_dir_all(&root).unwrap();
+}
@@ -0,0 +1,256 @@
+use crate::{
+    args::{Input, RunArgs},
+    output::Solved,
+};
+use solution::Day;
+use std::{
+    any::Any,
+    collections::BTreeMap,
+    panic::{self, AssertUnwindSafe},
+    path::Path,
+    sync::{
+        atomic::{AtomicUsize, Ordering},
+        mpsc,
+    },
+    thread,
+    time::Instant,
+};
+
+/// Answers of a day, or why it was not solved.
+pub struct DayResult {
+    pub day: Day,
+    pub input: String,
+    pub outcome: Outcome,
+}
+
+pub enum Outcome {
+    Solved(Vec<Solved>),
+    /// The input of the day is missing or empty, as it has not been added yet.
+    Skipped(String),
+    /// The input or the answers could not be read.
+    Failed(String),
+}
+
+impl DayResult {
+    /// Whether the day was skipped, or its input could be read and no part
+    /// panicked or was wrong.
+    pub fn passed(&self) -> bool {
+        match &self.outcome {
+            Outcome::Solved(solved) => solved.iter().all(Solved::passed),
+            Outcome::Skipped(_) => true,
+            Outcome::Failed(_) => false,
+        }
+    }
+}
+
+/// Solve the days on `jobs` threads. `report` gets the results in the order
+/// of the days, each as soon as it and every day before it is solved.
+pub fn solve_all(days: &[Day], args: &RunArgs, jobs: usize, mut report: impl FnMut(DayResult)) {
+    let next = AtomicUsize::new(0);
+    let (sender, receiver) = mpsc::channel();
+
+    thread::scope(|scope| {
+        for _ in 0..jobs.clamp(1, days.len().max(1)) {
+            let sender = sender.clone();
+            let next = &next;
+            scope.spawn(move || {
+                while let Some(&day) = days.get(next.fetch_add(1, Ordering::Relaxed)) {
+                    if sender.send((day.day, solve_day(day, args))).is_err() {
+                        break;
+                    }
+                }
+            });
+        }
+        drop(sender);
+
+        // Results arrive in any order, hold them back until the earlier days are done.
+        let mut order = days.iter().map(|day| day.day);
+        let mut waiting = order.next();
+        let mut pending = BTreeMap::new();
+        for (number, result) in receiver {
+            pending.insert(number, result);
+            while let Some(result) = waiting.and_then(|number| pending.remove(&number)) {
+                report(result);
+                waiting = order.next();
+            }
+        }
+    });
+}
+
+/// Solve the chosen parts of a day. A panic fails only the part it happened in,
+/// or every part when it happened while parsing the input.
+fn solve_day(day: Day, args: &RunArgs) -> DayResult {
+    let (input, kind) = crate::input_path(&day, &args.input);
+    let result = |outcome| DayResult {
+        day,
+        input: input.clone(),
+        outcome,
+    };
+
+    // The inputs of a day are added by hand, a file given with --input has to exist.
+    if !matches!(args.input, Input::Path(_)) && solution::missing(Path::new(&input)) {
+        return result(Outcome::Skipped(format!("{} is missing or empty.", input)));
+    }
+
+    let reader = match reader::try_open(&input) {
+        Ok(reader) => reader,
+        Err(err) => return result(Outcome::Failed(err.to_string())),
+    };
+
+    // Answers given with --input are not confirmed for any file.
+    let answers = match &args.input {
+        Input::Path(_) => None,
+        _ => match day.answers() {
+            Ok(answers) => Some(answers),
+            Err(err) => return result(Outcome::Failed(err)),
+        },
+    };
+
+    let parsed = panic::catch_unwind(AssertUnwindSafe(|| day.parse(reader))).map_err(panic_message);
+
+    let solved = args
+        .parts
+        .iter()
+        .map(|&part| {
+            let start = Instant::now();
+            let answer = match &parsed {
+                Ok(parsed) => {
+                    panic::catch_unwind(AssertUnwindSafe(|| day.solve_parsed(part, parsed, kind)))
+                        .map_err(panic_message)
+                }
+                Err(message) => Err(message.clone()),
+            };
+            let time = start.elapsed();
+
+            let verdict = match (&answers, &answer) {
+                (Some(answers), Ok(answer)) => Some(answers.check(kind, part, answer)),
+                _ => None,
+            };
+
+            Solved {
+                part,
+                answer,
+                time,
+                verdict,
+            }
+        })
+        .collect();
+
+    result(Outcome::Solved(solved))
+}
+
+/// Message given to `panic!`, for reporting a caught panic.
+pub fn panic_message(payload: Box<dyn Any + Send>) -> String {
+    match payload.downcast::<String>() {
+        Ok(message) => *message,
+        Err(payload) => match payload.downcast::<&str>() {
+            Ok(message) => message.to_string(),
+            Err(_) => "panicked".to_string(),
+        },
+    }
+}
+
+/// Stand-in that answers the number of lines, slowly so that it finishes last.
+#[cfg(test)]
+struct Counting;
+
+#[cfg(test)]
+impl solution::Solution for Counting {
+    const DAY: u8 = 1;
+    const TITLE: &'static str = "Counting";
+    const DIR: &'static str = "";
+
+    type Input = Vec<String>;
+    type PartOne = usize;
+    type PartTwo = usize;
+
+    fn parse(input: reader::Reader) -> Vec<String> {
+        thread::sleep(std::time::Duration::from_millis(50));
+        input.lines()
+    }
+
+    fn part_one(input: &Vec<String>) -> usize {
+        input.len()
+    }
+
+    fn part_two(input: &Vec<String>) -> usize {
+        input.len()
+    }
+}
+
+/// Stand-in whose part one panics.
+#[cfg(test)]
+struct Panicking;
+
+#[cfg(test)]
+impl solution::Solution for Panicking {
+    const DAY: u8 = 2;
+    const TITLE: &'static str = "Panicking";
+    const DIR: &'static str = "";
+
+    type Input = ();
+    type PartOne = usize;
+    type PartTwo = usize;
+
+    fn parse(_input: reader::Reader) {}
+
+    fn part_one(_input: &()) -> usize {
+        panic!("part one failed")
+    }
+
+    fn part_two(_input: &()) -> usize {
+        2
+    }
+}
+
+#[test]
+fn test_panic_isolation() {
+    use crate::args::{Format, RunArgs, Selection};
+    use solution::Part;
+
+    let dir = std::env::temp_dir().join(format!("aoc-solve-all-{}", std::process::id()));
+    std::fs::create_dir_all(&dir).unwrap();
+    std::fs::write(dir.join("input_example.txt"), "a\nb\nc\n").unwrap();
+    let dir: &'static str = Box::leak(dir.to_string_lossy().into_owned().into_boxed_str());
+
+    let days = [Day::of::<Counting>(), Day::of::<Panicking>()].map(|mut day| {
+        day.dir = dir;
+        day
+    });
+    let args = RunArgs {
+        days: Selection::All,
+        parts: Part::BOTH.to_vec(),
+        input: Input::Example,
+        format: Format::Json,
+        jobs: 2,
+    };
+    let mut results = vec![];
+    solve_all(&days, &args, 2, |result| results.push(result));
+
+    // The panicking day is solved first, but reported after the day before it.
+    let numbers: Vec<u8> = results.iter().map(|result| result.day.day).collect();
+    assert_eq!(numbers, vec![1, 2]);
+    assert!(results[0].passed());
+    assert!(!results[1].passed());
+
+    let summary = crate::output::summary(&results);
+    let panicked: Vec<&str> = summary
+        .lines()
+        .filter(|line| line.ends_with("panicked"))
+        .collect();
+    assert_eq!(panicked.len(), 1);
+    assert_eq!(
+        panicked[0].split_whitespace().take(3).collect::<Vec<_>>(),
+        ["2", "1", "-"]
+    );
+    assert!(summary.ends_with("1 panicked, 0 days skipped, 0 days unreadable\n"));
+
+    let json = crate::output::json(&results);
+    let parts = &json["days"][1]["parts"];
+    assert_eq!(json["days"][0]["parts"][0]["answer"]["value"], 3);
+    assert_eq!(parts[0]["status"], "panicked");
+    assert_eq!(parts[0]["error"], "part one failed");
+    assert_eq!(parts[1]["answer"]["value"], 2);
+
+    std::fs::remove_dir_all(dir).unwrap();
+}
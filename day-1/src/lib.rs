@@ -0,0 +1,47 @@
+use solution::Solution;
+
+fn summed_food(input: reader::Reader) -> Vec<usize> {
+    input
+        .blocks_of::<usize>()
+        .into_iter()
+        .map(|food| food.iter().sum())
+        .collect()
+}
+
+fn part_one(elves: &[usize]) -> usize {
+    *elves.iter().max().unwrap()
+}
+
+fn part_two(elves: &[usize]) -> usize {
+    let mut elves = elves.to_vec();
+    elves.sort();
+    elves.reverse();
+    let top_three = &elves[0..3];
+    top_three.iter().sum()
+}
+
+pub struct Day1;
+
+impl Solution for Day1 {
+    const DAY: u8 = 1;
+    const TITLE: &'static str = "Calorie Counting";
+    const DIR: &'static str = env!("CARGO_MANIFEST_DIR");
+
+    type Input = Vec<usize>;
+    type PartOne = usize;
+    type PartTwo = usize;
+
+    fn parse(input: reader::Reader) -> Vec<usize> {
+        summed_food(input)
+    }
+
+    fn part_one(input: &Vec<usize>) -> usize {
+        part_one(input)
+    }
+
+    fn part_two(input: &Vec<usize>) -> usize {
+        part_two(input)
+    }
+}
+
+solution::answer_tests!(Day1);
@@ -0,0 +1,87 @@
+use reader::{self, PuzzleParse};
+use solution::Solution;
+
+#[derive(PuzzleParse)]
+enum Instruction {
+    #[parse("noop")]
+    Noop,
+    #[parse("addx {0}")]
+    Addx(isize),
+}
+
+fn get_cycle_changes(input: reader::Reader) -> Vec<isize> {
+    input
+        .lines_as::<Instruction>()
+        .iter()
+        .flat_map(|value| match value {
+            Instruction::Noop => vec![0],
+            Instruction::Addx(x) => vec![0, *x],
+        })
+        .collect::<Vec<isize>>()
+}
+
+fn part_one(cycles: &[isize]) -> isize {
+    let mut res = 0;
+    let mut x = 1;
+
+    for (i, change) in cycles.iter().enumerate() {
+        let cycle = i as isize + 1;
+
+        if (cycle - 20) % 40 == 0 {
+            res += cycle * x;
+        }
+
+        x += change;
+    }
+
+    res
+}
+
+fn part_two(cycles: &[isize]) -> String {
+    let mut res: Vec<Vec<char>> = vec![];
+    let mut x = 1;
+
+    for row in 0..6 {
+        let mut line = vec![];
+        for col in 0..40 {
+            if col - 1 == x || col == x || col + 1 == x {
+                line.push('#');
+            } else {
+                line.push('.');
+            }
+            x += cycles[(row * 40 + col) as usize];
+        }
+        res.push(line);
+    }
+
+    res.iter()
+        .map(|line| line.iter().collect::<String>())
+        .collect::<Vec<String>>()
+        .join("\n")
+}
+
+pub struct Day10;
+
+impl Solution for Day10 {
+    const DAY: u8 = 10;
+    const TITLE: &'static str = "Cathode-Ray Tube";
+    const DIR: &'static str = env!("CARGO_MANIFEST_DIR");
+
+    type Input = Vec<isize>;
+    type PartOne = isize;
+    type PartTwo = String;
+
+    fn parse(input: reader::Reader) -> Vec<isize> {
+        get_cycle_changes(input)
+    }
+
+    fn part_one(input: &Vec<isize>) -> isize {
+        part_one(input)
+    }
+
+    fn part_two(input: &Vec<isize>) -> String {
+        part_two(input)
+    }
+}
+
+solution::answer_tests!(Day10);
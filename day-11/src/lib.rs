@@ -0,0 +1,176 @@
+use reader::{self, PuzzleParse};
+use solution::Solution;
+use std::{str::FromStr, string::ParseError};
+
+#[derive(Clone)]
+enum Num {
+    Old,
+    Num(usize),
+}
+
+impl Num {
+    fn get_value(&self, old: usize) -> usize {
+        match self {
+            Num::Old => old,
+            Num::Num(value) => *value,
+        }
+    }
+}
+
+#[derive(Clone)]
+struct Op {
+    left: Num,
+    right: Num,
+    op: String,
+}
+
+impl FromStr for Op {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let ss = s.split(" ").collect::<Vec<&str>>();
+
+        let left = Op::num_or_old(ss[0]);
+        let right = Op::num_or_old(ss[2]);
+
+        Ok(Op {
+            left,
+            right,
+            op: ss[1].to_string(),
+        })
+    }
+}
+
+impl Op {
+    fn num_or_old(s: &str) -> Num {
+        match s {
+            "old" => Num::Old,
+            _ => Num::Num(s.parse().unwrap()),
+        }
+    }
+
+    fn run(&self, old: usize) -> usize {
+        let l = self.left.get_value(old);
+        let r = self.right.get_value(old);
+
+        match self.op.as_str() {
+            "*" => l * r,
+            "+" => l + r,
+            _ => panic!("Unknown op"),
+        }
+    }
+}
+
+#[derive(Clone, PuzzleParse)]
+#[parse("divisible by {modulo}\n    If true: throw to monkey {if_true}\n    If false: throw to monkey {if_false}")]
+struct Test {
+    if_true: usize,
+    if_false: usize,
+    modulo: usize,
+}
+
+impl Test {
+    fn run(&self, item: usize) -> usize {
+        match item % self.modulo {
+            0 => self.if_true,
+            _ => self.if_false,
+        }
+    }
+}
+
+#[derive(Clone, PuzzleParse)]
+#[parse("Monkey {_}:\n  Starting items: {items:, }\n  Operation: new = {op}\n  Test: {test}")]
+pub struct Monkey {
+    op: Op,
+    test: Test,
+    items: Vec<usize>,
+    count: usize,
+}
+
+struct Monkeys {
+    monkeys: Vec<Monkey>,
+    relaxer: Box<dyn Fn(usize) -> usize>,
+}
+
+impl Monkeys {
+    fn from(monkeys: Vec<Monkey>, relaxer: Box<dyn Fn(usize) -> usize>) -> Monkeys {
+        Monkeys { monkeys, relaxer }
+    }
+
+    fn round(&mut self) {
+        for index in 0..self.monkeys.len() {
+            let monkey = &mut self.monkeys[index];
+
+            let to_add = monkey
+                .items
+                .iter()
+                .map(|item| monkey.op.run(*item))
+                .map(|item| self.relaxer.as_ref()(item))
+                .map(|item| (monkey.test.run(item), item))
+                .collect::<Vec<(usize, usize)>>();
+
+            monkey.items.clear();
+            monkey.count += to_add.len();
+
+            to_add
+                .iter()
+                .for_each(|(to, item)| self.monkeys[*to].items.push(*item));
+        }
+    }
+
+    fn get_level_of_monkey_business(&mut self) -> usize {
+        self.monkeys
+            .sort_by_key(|monkey| std::cmp::Reverse(monkey.count));
+        self.monkeys
+            .iter()
+            .map(|monkey| monkey.count)
+            .take(2)
+            .product()
+    }
+}
+
+fn part_one(parsed_input: &[Monkey]) -> usize {
+    let mut monkeys = Monkeys::from(parsed_input.to_vec(), Box::from(|x| x / 3));
+
+    (0..20).for_each(|_| monkeys.round());
+
+    monkeys.get_level_of_monkey_business()
+}
+
+fn part_two(parsed_input: &[Monkey]) -> usize {
+    let modulo: usize = parsed_input
+        .iter()
+        .map(|monkey| monkey.test.modulo)
+        .product();
+    let mut monkeys = Monkeys::from(parsed_input.to_vec(), Box::from(move |x| x % modulo));
+
+    (0..10000).for_each(|_| monkeys.round());
+
+    monkeys.get_level_of_monkey_business()
+}
+
+pub struct Day11;
+
+impl Solution for Day11 {
+    const DAY: u8 = 11;
+    const TITLE: &'static str = "Monkey in the Middle";
+    const DIR: &'static str = env!("CARGO_MANIFEST_DIR");
+
+    type Input = Vec<Monkey>;
+    type PartOne = usize;
+    type PartTwo = usize;
+
+    fn parse(input: reader::Reader) -> Vec<Monkey> {
+        input.split_on_empty_line_into::<Monkey>()
+    }
+
+    fn part_one(input: &Vec<Monkey>) -> usize {
+        part_one(input)
+    }
+
+    fn part_two(input: &Vec<Monkey>) -> usize {
+        part_two(input)
+    }
+}
+
+solution::answer_tests!(Day11);
@@ -0,0 +1,141 @@
+use reader::{self, Grid};
+use solution::Solution;
+use std::cmp::min;
+use std::collections::VecDeque;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+struct Position {
+    x: usize,
+    y: usize,
+}
+
+impl Position {
+    fn from((x, y): (usize, usize)) -> Position {
+        Position { x, y }
+    }
+}
+
+#[derive(Clone)]
+pub struct Heightmap {
+    start: Position,
+    end: Position,
+    map: Grid<i32>,
+    height: usize,
+    width: usize,
+}
+
+impl Heightmap {
+    fn from(input: reader::Reader) -> Heightmap {
+        let (start, end, map) = Heightmap::parse_map(input);
+
+        Heightmap {
+            start,
+            end,
+            height: map.height(),
+            width: map.width(),
+            map,
+        }
+    }
+
+    fn parse_map(input: reader::Reader) -> (Position, Position, Grid<i32>) {
+        let chars = input.grid::<char>();
+
+        let start = Position::from(chars.find(&'S').expect("No start marker"));
+        let end = Position::from(chars.find(&'E').expect("No end marker"));
+
+        let map = chars.map(|char| {
+            let char = match char {
+                'S' => 'a',
+                'E' => 'z',
+                value => *value,
+            };
+
+            char as i32 - 'a' as i32
+        });
+
+        (start, end, map)
+    }
+
+    fn get_adjacency_list(&self, pos: Position) -> Vec<Position> {
+        self.map
+            .neighbours4((pos.x, pos.y))
+            .map(Position::from)
+            .filter(|elem| self.get_height(*elem) - self.get_height(pos) <= 1)
+            .collect()
+    }
+
+    fn get_height(&self, pos: Position) -> i32 {
+        self.map[(pos.x, pos.y)]
+    }
+
+    fn bfs(&self) -> i32 {
+        let mut q = VecDeque::new();
+        let mut explored = vec![vec![false; self.width]; self.height];
+
+        explored[self.start.x][self.start.y] = true;
+        q.push_back((0, self.start));
+
+        while !q.is_empty() {
+            let (len, curr) = q.pop_front().unwrap();
+
+            if self.end.x == curr.x && self.end.y == curr.y {
+                return len;
+            }
+
+            self.get_adjacency_list(curr).iter().for_each(|pos| {
+                if !explored[pos.x][pos.y] {
+                    q.push_back((len + 1, *pos));
+                    explored[pos.x][pos.y] = true;
+                }
+            })
+        }
+
+        i32::MAX
+    }
+}
+
+fn part_one(map: &Heightmap) -> i32 {
+    map.bfs()
+}
+
+fn part_two(map: &Heightmap) -> i32 {
+    let mut map = map.clone();
+
+    let mut shortest = map.bfs();
+
+    for pos in map.map.positions() {
+        if map.get_height(Position::from(pos)) == 0 {
+            map.start = Position::from(pos);
+            let len = map.bfs();
+            shortest = min(shortest, len)
+        }
+    }
+
+    shortest
+}
+
+pub struct Day12;
+
+impl Solution for Day12 {
+    const DAY: u8 = 12;
+    const TITLE: &'static str = "Hill Climbing Algorithm";
+    const DIR: &'static str = env!("CARGO_MANIFEST_DIR");
+
+    type Input = Heightmap;
+    type PartOne = i32;
+    type PartTwo = i32;
+
+    fn parse(input: reader::Reader) -> Heightmap {
+        Heightmap::from(input)
+    }
+
+    fn part_one(input: &Heightmap) -> i32 {
+        part_one(input)
+    }
+
+    fn part_two(input: &Heightmap) -> i32 {
+        part_two(input)
+    }
+}
+
+solution::answer_tests!(Day12);
@@ -0,0 +1,3 @@
+fn main() {
+    solution::build::answer_test_cfgs();
+}
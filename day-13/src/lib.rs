@@ -0,0 +1,180 @@
+use solution::Solution;
+use std::cmp::Ordering;
+use std::collections::VecDeque;
+use std::iter::Peekable;
+use std::str::Chars;
+use std::{str::FromStr, string::ParseError};
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Value {
+    List(VecDeque<Value>),
+    Integer(isize),
+}
+
+impl FromStr for Value {
+    type Err = ParseError;
+
+    fn from_str(str: &str) -> Result<Self, Self::Err> {
+        Ok(Self::list(&mut str.chars().peekable()))
+    }
+}
+
+impl Value {
+    fn list(iter: &mut Peekable<Chars>) -> Value {
+        let mut values = VecDeque::new();
+
+        iter.next();
+
+        loop {
+            match *iter.peek().unwrap() {
+                ',' => {
+                    iter.next();
+                }
+                '0'..='9' => {
+                    values.push_back(Self::integer(iter));
+                }
+                '[' => values.push_back(Self::list(iter)),
+                ']' => {
+                    iter.next();
+                    break;
+                }
+                value => panic!("Unexpected char: {:?}", value),
+            };
+        }
+
+        Value::List(values)
+    }
+
+    fn integer(iter: &mut Peekable<Chars>) -> Value {
+        let mut num_s = String::new();
+
+        while iter.peek().unwrap().is_numeric() {
+            num_s.push(iter.next().unwrap());
+        }
+
+        Value::Integer(num_s.parse::<isize>().unwrap())
+    }
+}
+
+fn order(left: &mut Value, right: &mut Value) -> Ordering {
+    /*
+    Finds the order of two lists recursively.
+     */
+    match (left, right) {
+        // Case: Both values are integers
+        (Value::Integer(a), Value::Integer(b)) => (*a).cmp(b),
+        // Case: Both values are lists
+        (Value::List(a), Value::List(b)) => {
+            /*
+            While either list contains a value we compare the values
+            untill we find one that isn't equal. If list runs out of
+            elements default value of -1 is returned meaning that list
+            win the next comparison.
+            */
+            let mut l;
+            let mut r;
+
+            while !a.is_empty() || !b.is_empty() {
+                l = a.pop_front().unwrap_or(Value::Integer(-1));
+                r = b.pop_front().unwrap_or(Value::Integer(-1));
+
+                let res = order(&mut l, &mut r);
+
+                if res != Ordering::Equal {
+                    return res;
+                }
+            }
+
+            // Default case when both lists run out of elements at the same time
+            Ordering::Equal
+        }
+        // Case: Left = Integer, Right = List
+        (Value::Integer(a), b) => order(
+            &mut Value::List(VecDeque::from(vec![Value::Integer(*a)])),
+            b,
+        ),
+        // Case: Left = List, Right = Integer
+        (a, Value::Integer(b)) => order(
+            a,
+            &mut Value::List(VecDeque::from(vec![Value::Integer(*b)])),
+        ),
+    }
+}
+
+fn part_one(pairs: &[(Value, Value)]) -> usize {
+    // Compare pairs and sum indexes where pairs are in
+    // correct order (With +1 offset).
+    let mut sum = 0;
+
+    // Values are cloned as order() mutates them.
+    for (index, (l, r)) in pairs.iter().enumerate() {
+        if order(&mut l.clone(), &mut r.clone()) == Ordering::Less {
+            sum += 1 + index;
+        }
+    }
+
+    sum
+}
+
+fn part_two(pairs: &[(Value, Value)]) -> usize {
+    let divider_packets = ["[[2]]".to_string(), "[[6]]".to_string()];
+
+    // Take every packet of the pairs and add the divider packets.
+    let mut values = pairs
+        .iter()
+        .flat_map(|(l, r)| [l.clone(), r.clone()])
+        .chain(
+            divider_packets
+                .iter()
+                .map(|line| line.parse::<Value>().unwrap()),
+        )
+        .collect::<Vec<Value>>();
+
+    // Sort with order(). Values are cloned as order() mutates them.
+    values.sort_by(|a, b| order(&mut a.clone(), &mut b.clone()));
+
+    // Find indexes of divider packets
+    let mut first = 0;
+    let mut second = 0;
+
+    let first_val = "[[2]]".parse::<Value>().unwrap();
+    let second_val = "[[6]]".parse::<Value>().unwrap();
+
+    for (index, value) in values.iter().enumerate() {
+        if value == &first_val {
+            first = index;
+        }
+
+        if value == &second_val {
+            second = index;
+        }
+    }
+
+    (first + 1) * (second + 1)
+}
+
+pub struct Day13;
+
+impl Solution for Day13 {
+    const DAY: u8 = 13;
+    const TITLE: &'static str = "Distress Signal";
+    const DIR: &'static str = env!("CARGO_MANIFEST_DIR");
+
+    type Input = Vec<(Value, Value)>;
+    type PartOne = usize;
+    type PartTwo = usize;
+
+    fn parse(input: reader::Reader) -> Vec<(Value, Value)> {
+        input.pairs_of::<Value>()
+    }
+
+    fn part_one(input: &Vec<(Value, Value)>) -> usize {
+        part_one(input)
+    }
+
+    fn part_two(input: &Vec<(Value, Value)>) -> usize {
+        part_two(input)
+    }
+}
+
+solution::answer_tests!(Day13);
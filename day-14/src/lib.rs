@@ -0,0 +1,161 @@
+use reader::PuzzleParse;
+use solution::Solution;
+use std::cmp::{max, min};
+use std::collections::HashSet;
+use std::ops;
+
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Hash, PuzzleParse)]
+#[parse("{x},{y}")]
+struct Point {
+    x: isize,
+    y: isize,
+}
+
+impl ops::Add<Point> for Point {
+    type Output = Point;
+
+    fn add(self, rhs: Point) -> Point {
+        Point {
+            x: self.x + rhs.x,
+            y: self.y + rhs.y,
+        }
+    }
+}
+
+#[derive(Debug, Clone, PuzzleParse)]
+#[parse("{points: -> }")]
+struct Rock {
+    points: Vec<Point>,
+}
+
+#[derive(Debug, Clone)]
+pub struct Map {
+    rocks: Vec<Rock>,
+    map: HashSet<Point>,
+    max_y: isize,
+}
+
+impl Map {
+    fn from(input: reader::Reader) -> Map {
+        let mut map = Map {
+            rocks: input.lines_as::<Rock>(),
+            map: HashSet::new(),
+            max_y: 0,
+        };
+
+        for rock in map.rocks.iter() {
+            for i in 1..rock.points.len() {
+                let prev = rock.points[i - 1];
+                let curr = rock.points[i];
+
+                // Only one pair of these should be different.
+                let min_x = min(prev.x, curr.x);
+                let max_x = max(prev.x, curr.x);
+
+                let min_y = min(prev.y, curr.y);
+                let max_y = max(prev.y, curr.y);
+
+                // Max y is used to determine if sand has fallen to void.
+                map.max_y = max(max_y, map.max_y);
+
+                for x in min_x..=max_x {
+                    for y in min_y..=max_y {
+                        map.map.insert(Point { x, y });
+                    }
+                }
+            }
+        }
+
+        map
+    }
+
+    fn drop_sand_part_one(&mut self) -> bool {
+        let dropped = self.drop_sand();
+        self.map.insert(dropped);
+
+        dropped.y > self.max_y
+    }
+
+    fn drop_sand_part_two(&mut self) -> bool {
+        let dropped = self.drop_sand();
+        self.map.insert(dropped);
+
+        dropped == Point { x: 500, y: 0 }
+    }
+
+    fn drop_sand(&mut self) -> Point {
+        let mut curr = Point { x: 500, y: 0 };
+
+        loop {
+            let down = curr + Point { x: 0, y: 1 };
+            let left = curr + Point { x: -1, y: 1 };
+            let right = curr + Point { x: 1, y: 1 };
+
+            if self.is_free(&down) {
+                curr = down;
+            } else if self.is_free(&left) {
+                curr = left;
+            } else if self.is_free(&right) {
+                curr = right;
+            } else {
+                break;
+            }
+        }
+
+        curr
+    }
+
+    fn is_free(&self, p: &Point) -> bool {
+        p.y < self.max_y + 2 && !self.map.contains(p)
+    }
+}
+
+fn part_one(map: &Map) -> usize {
+    let mut map = map.clone();
+
+    let mut count = 0;
+
+    while !map.drop_sand_part_one() {
+        count += 1;
+    }
+
+    count
+}
+
+fn part_two(map: &Map) -> usize {
+    let mut map = map.clone();
+
+    let mut count = 0;
+
+    while !map.drop_sand_part_two() {
+        count += 1;
+    }
+
+    count + 1
+}
+
+pub struct Day14;
+
+impl Solution for Day14 {
+    const DAY: u8 = 14;
+    const TITLE: &'static str = "Regolith Reservoir";
+    const DIR: &'static str = env!("CARGO_MANIFEST_DIR");
+
+    type Input = Map;
+    type PartOne = usize;
+    type PartTwo = usize;
+
+    fn parse(input: reader::Reader) -> Map {
+        Map::from(input)
+    }
+
+    fn part_one(input: &Map) -> usize {
+        part_one(input)
+    }
+
+    fn part_two(input: &Map) -> usize {
+        part_two(input)
+    }
+}
+
+solution::answer_tests!(Day14);
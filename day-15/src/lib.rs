@@ -0,0 +1,170 @@
+use solution::{InputKind, Solution};
+use std::cmp::max;
+
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Hash)]
+struct Range {
+    start: isize,
+    end: isize,
+}
+
+impl Range {
+    fn from(start: isize, end: isize) -> Range {
+        Range { start, end }
+    }
+
+    fn len(&self) -> isize {
+        self.end - self.start
+    }
+
+    fn common(&self, other_end: isize) -> isize {
+        if self.end <= other_end {
+            self.len()
+        } else if self.start <= other_end {
+            // Range end is inclusive
+            other_end - self.start
+        } else {
+            0
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Hash)]
+struct Point {
+    x: isize,
+    y: isize,
+}
+
+impl Point {
+    fn from(x: isize, y: isize) -> Point {
+        Point { x, y }
+    }
+
+    fn manhattan_distance(&self, other: Point) -> isize {
+        (self.x - other.x).abs() + (self.y - other.y).abs()
+    }
+}
+
+pub struct Sensor {
+    at: Point,
+    beacon: Point,
+}
+
+impl From<[isize; 4]> for Sensor {
+    fn from([x, y, bx, by]: [isize; 4]) -> Self {
+        Sensor {
+            at: Point::from(x, y),
+            beacon: Point::from(bx, by),
+        }
+    }
+}
+
+impl Sensor {
+    fn closest_len(&self) -> isize {
+        self.at.manhattan_distance(self.beacon)
+    }
+
+    fn range_at_row(&self, y: isize) -> Option<Range> {
+        // Returns the range of cover.
+        let extras = self.closest_len() - self.at.manhattan_distance(Point { x: self.at.x, y });
+
+        if extras < 0 {
+            None
+        } else {
+            Some(Range::from(self.at.x - extras, self.at.x + extras))
+        }
+    }
+
+    fn is_covering(&self, p: Point) -> bool {
+        self.at.manhattan_distance(p) <= self.closest_len()
+    }
+}
+
+fn part_one(sensors: &[Sensor], y: isize) -> isize {
+    let mut ranges = sensors
+        .iter()
+        .filter_map(|sensor| sensor.range_at_row(y))
+        .collect::<Vec<Range>>();
+
+    ranges.sort_by_key(|a| a.start);
+
+    let mut len = 0;
+    let mut max_end = -2_000_000_000;
+
+    for range in ranges.iter() {
+        len += range.len() - range.common(max_end);
+        max_end = max(max_end, range.end);
+    }
+
+    len
+}
+
+fn part_two(sensors: &[Sensor], y: isize) -> isize {
+    let mut res = Point::from(0, 0);
+
+    for i in 0..=y {
+        let mut x = 0;
+        while x <= y {
+            let mut x_xhanged = false;
+            for sensor in sensors.iter() {
+                if sensor.is_covering(Point::from(x, i)) {
+                    x_xhanged = true;
+                    x = sensor.range_at_row(i).unwrap().end + 1;
+                    break;
+                }
+            }
+            if !x_xhanged {
+                break;
+            }
+        }
+        if x <= y {
+            res = Point::from(x, i);
+            break;
+        }
+    }
+
+    res.x * 4_000_000 + res.y
+}
+
+pub struct Day15;
+
+impl Solution for Day15 {
+    const DAY: u8 = 15;
+    const TITLE: &'static str = "Beacon Exclusion Zone";
+    const DIR: &'static str = env!("CARGO_MANIFEST_DIR");
+
+    type Input = Vec<Sensor>;
+    type PartOne = isize;
+    type PartTwo = isize;
+
+    fn parse(input: reader::Reader) -> Vec<Sensor> {
+        input
+            .ints_per_line_array::<isize, 4>()
+            .into_iter()
+            .map(Sensor::from)
+            .collect()
+    }
+
+    fn part_one(input: &Vec<Sensor>) -> isize {
+        Self::part_one_for(input, InputKind::Real)
+    }
+
+    fn part_two(input: &Vec<Sensor>) -> isize {
+        Self::part_two_for(input, InputKind::Real)
+    }
+
+    fn part_one_for(input: &Vec<Sensor>, kind: InputKind) -> isize {
+        match kind {
+            InputKind::Example => part_one(input, 10),
+            InputKind::Real => part_one(input, 2_000_000),
+        }
+    }
+
+    fn part_two_for(input: &Vec<Sensor>, kind: InputKind) -> isize {
+        match kind {
+            InputKind::Example => part_two(input, 20),
+            InputKind::Real => part_two(input, 4_000_000),
+        }
+    }
+}
+
+solution::answer_tests!(Day15);
@@ -0,0 +1,127 @@
+use reader::{self, PuzzleParse};
+use solution::Solution;
+use std::cmp::max;
+use std::collections::{HashMap, HashSet, VecDeque};
+
+#[derive(Debug, Clone, PuzzleParse)]
+#[parse("Valve {name} has flow rate={flow}; tunnels lead to valves {tunnels:, }")]
+#[parse("Valve {name} has flow rate={flow}; tunnel leads to valve {tunnels:, }")]
+struct Valve {
+    name: String,
+    flow: isize,
+    tunnels: Vec<String>,
+    len_to: HashMap<String, isize>,
+}
+
+#[derive(Debug)]
+pub struct Graph {
+    map: HashMap<String, Valve>,
+}
+
+impl Graph {
+    fn from(input: reader::Reader) -> Graph {
+        let mut valves = input.lines_as::<Valve>();
+
+        // For each valve calculate path length to every other valve
+        let mut bfs_map = HashMap::new();
+        for valve in valves.iter() {
+            bfs_map.insert(valve.name.clone(), valve.clone());
+        }
+
+        for valve in valves.iter_mut() {
+            Self::bfs(valve, &bfs_map);
+        }
+
+        // Create final map of valves
+        let mut map = HashMap::new();
+
+        for valve in valves.iter() {
+            map.insert(valve.name.clone(), valve.clone());
+        }
+
+        Graph { map }
+    }
+
+    fn bfs(valve: &mut Valve, map: &HashMap<String, Valve>) {
+        let mut q: VecDeque<(isize, String)> = VecDeque::new();
+        let mut visited: HashSet<String> = HashSet::new();
+
+        q.push_back((0, valve.name.clone()));
+        visited.insert(valve.name.clone());
+
+        while !q.is_empty() {
+            let (len, v) = q.pop_front().unwrap();
+            let v_valve = map.get(&v).unwrap();
+
+            v_valve.tunnels.iter().for_each(|tunnel| {
+                if !visited.contains(tunnel) {
+                    let curr = map.get(tunnel).unwrap();
+
+                    // It seems like there would be no reason to
+                    // have paths to damaged/jammed valves
+                    if curr.flow != 0 {
+                        valve.len_to.insert(tunnel.clone(), len + 1);
+                    }
+
+                    visited.insert(tunnel.clone());
+                    q.push_back((len + 1, tunnel.clone()));
+                }
+            });
+        }
+    }
+
+    fn max_flow(&self, current: String, mut visited: HashSet<String>, mut time: isize) -> isize {
+        let curr = self.map.get(&current).unwrap();
+        visited.insert(current.clone());
+
+        let mut flow = 0;
+
+        if curr.flow > 0 {
+            time -= 1;
+            flow += curr.flow * time;
+        }
+
+        let mut m: isize = 0;
+        for (dest, len) in curr.len_to.iter() {
+            if time - len >= 2 && !visited.contains(dest) {
+                m = max(m, self.max_flow(dest.clone(), visited.clone(), time - len));
+            }
+        }
+
+        flow + m
+    }
+}
+
+fn part_one(g: &Graph) -> isize {
+    g.max_flow("AA".to_string(), HashSet::new(), 30)
+}
+
+fn part_two(_input: &Graph) -> usize {
+    0
+}
+
+pub struct Day16;
+
+impl Solution for Day16 {
+    const DAY: u8 = 16;
+    const TITLE: &'static str = "Proboscidea Volcanium";
+    const DIR: &'static str = env!("CARGO_MANIFEST_DIR");
+
+    type Input = Graph;
+    type PartOne = isize;
+    type PartTwo = usize;
+
+    fn parse(input: reader::Reader) -> Graph {
+        Graph::from(input)
+    }
+
+    fn part_one(input: &Graph) -> isize {
+        part_one(input)
+    }
+
+    fn part_two(input: &Graph) -> usize {
+        part_two(input)
+    }
+}
+
+solution::answer_tests!(Day16);
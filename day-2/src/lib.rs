@@ -0,0 +1,70 @@
+use solution::Solution;
+
+fn line_score_part_one((a, b): (char, char)) -> usize {
+    /*
+    Rock     - A - X
+    Paper    - B - Y
+    Scissors - C - Z
+    */
+    match (a, b) {
+        ('A', 'X') => 4,
+        ('A', 'Y') => 8,
+        ('A', 'Z') => 3,
+        ('B', 'X') => 1,
+        ('B', 'Y') => 5,
+        ('B', 'Z') => 9,
+        ('C', 'X') => 7,
+        ('C', 'Y') => 2,
+        ('C', 'Z') => 6,
+        (_, _) => 0,
+    }
+}
+
+fn line_score_part_two((a, b): (char, char)) -> usize {
+    match (a, b) {
+        ('A', 'X') => 3,
+        ('A', 'Y') => 4,
+        ('A', 'Z') => 8,
+        ('B', 'X') => 1,
+        ('B', 'Y') => 5,
+        ('B', 'Z') => 9,
+        ('C', 'X') => 2,
+        ('C', 'Y') => 6,
+        ('C', 'Z') => 7,
+        (_, _) => 0,
+    }
+}
+
+fn part_one(rounds: &[(char, char)]) -> usize {
+    rounds.iter().copied().map(line_score_part_one).sum()
+}
+
+fn part_two(rounds: &[(char, char)]) -> usize {
+    rounds.iter().copied().map(line_score_part_two).sum()
+}
+
+pub struct Day2;
+
+impl Solution for Day2 {
+    const DAY: u8 = 2;
+    const TITLE: &'static str = "Rock Paper Scissors";
+    const DIR: &'static str = env!("CARGO_MANIFEST_DIR");
+
+    type Input = Vec<(char, char)>;
+    type PartOne = usize;
+    type PartTwo = usize;
+
+    fn parse(input: reader::Reader) -> Vec<(char, char)> {
+        input.columns::<(char, char)>(" ")
+    }
+
+    fn part_one(input: &Vec<(char, char)>) -> usize {
+        part_one(input)
+    }
+
+    fn part_two(input: &Vec<(char, char)>) -> usize {
+        part_two(input)
+    }
+}
+
+solution::answer_tests!(Day2);
@@ -0,0 +1,68 @@
+use solution::Solution;
+
+fn to_priority(c: char) -> usize {
+    match c {
+        'a'..='z' => c as usize - 'a' as usize + 1,
+        'A'..='Z' => c as usize - 'A' as usize + 1 + 26,
+        _ => panic!("Invalid character: {}", c),
+    }
+}
+
+fn find_common(a: Vec<char>, b: Vec<char>) -> Vec<char> {
+    a.into_iter().filter(|elem| b.contains(elem)).collect()
+}
+
+fn part_one(rucksacks: &[String]) -> usize {
+    rucksacks
+        .iter()
+        .map(|elem| {
+            find_common(
+                elem[..elem.len() / 2].chars().collect::<Vec<char>>(),
+                elem[elem.len() / 2..].chars().collect::<Vec<char>>(),
+            )
+        })
+        .map(|elem| to_priority(elem[0]))
+        .sum()
+}
+
+fn part_two(rucksacks: &[String]) -> usize {
+    rucksacks
+        .chunks(3)
+        .map(|chunk| {
+            find_common(
+                chunk[0].chars().collect::<Vec<char>>(),
+                find_common(
+                    chunk[1].chars().collect::<Vec<char>>(),
+                    chunk[2].chars().collect::<Vec<char>>(),
+                ),
+            )
+        })
+        .map(|elem| to_priority(elem[0]))
+        .sum()
+}
+
+pub struct Day3;
+
+impl Solution for Day3 {
+    const DAY: u8 = 3;
+    const TITLE: &'static str = "Rucksack Reorganization";
+    const DIR: &'static str = env!("CARGO_MANIFEST_DIR");
+
+    type Input = Vec<String>;
+    type PartOne = usize;
+    type PartTwo = usize;
+
+    fn parse(input: reader::Reader) -> Vec<String> {
+        input.lines()
+    }
+
+    fn part_one(input: &Vec<String>) -> usize {
+        part_one(input)
+    }
+
+    fn part_two(input: &Vec<String>) -> usize {
+        part_two(input)
+    }
+}
+
+solution::answer_tests!(Day3);
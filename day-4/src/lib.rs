@@ -0,0 +1,75 @@
+use reader::{self, PuzzleParse};
+use solution::Solution;
+
+#[derive(Clone, Debug, Copy, PuzzleParse)]
+#[parse("{begin}-{end}")]
+pub struct Range {
+    begin: usize,
+    end: usize,
+}
+
+fn get_ranges(input: reader::Reader) -> Vec<Vec<Range>> {
+    input
+        .parse_lines_iter(|line| {
+            line.split(",")
+                .map(|range| range.parse::<Range>().unwrap())
+                .collect::<Vec<Range>>()
+        })
+        .collect()
+}
+
+fn overlaps_part_one(a: Range, b: Range) -> usize {
+    if (a.begin <= b.begin && a.end >= b.end) || (b.begin <= a.begin && b.end >= a.end) {
+        1
+    } else {
+        0
+    }
+}
+
+fn overlaps_part_two(a: Range, b: Range) -> usize {
+    if a.end >= b.begin && a.begin <= b.end {
+        1
+    } else {
+        0
+    }
+}
+
+fn part_one(ranges: &[Vec<Range>]) -> usize {
+    ranges
+        .iter()
+        .map(|elem| overlaps_part_one(elem[0], elem[1]))
+        .sum()
+}
+
+fn part_two(ranges: &[Vec<Range>]) -> usize {
+    ranges
+        .iter()
+        .map(|elem| overlaps_part_two(elem[0], elem[1]))
+        .sum()
+}
+
+pub struct Day4;
+
+impl Solution for Day4 {
+    const DAY: u8 = 4;
+    const TITLE: &'static str = "Camp Cleanup";
+    const DIR: &'static str = env!("CARGO_MANIFEST_DIR");
+
+    type Input = Vec<Vec<Range>>;
+    type PartOne = usize;
+    type PartTwo = usize;
+
+    fn parse(input: reader::Reader) -> Vec<Vec<Range>> {
+        get_ranges(input)
+    }
+
+    fn part_one(input: &Vec<Vec<Range>>) -> usize {
+        part_one(input)
+    }
+
+    fn part_two(input: &Vec<Vec<Range>>) -> usize {
+        part_two(input)
+    }
+}
+
+solution::answer_tests!(Day4);
@@ -0,0 +1,136 @@
+use reader::{self, FromSection, PuzzleParse, ReaderError};
+use solution::Solution;
+
+#[derive(Debug, PuzzleParse)]
+#[parse("move {amount} from {from} to {to}")]
+pub struct Instruction {
+    amount: usize,
+    from: usize,
+    to: usize,
+}
+
+struct CrateMover {
+    stacks: Vec<Vec<String>>,
+    held_crates: Vec<String>,
+}
+
+impl CrateMover {
+    fn from(stacks: Vec<Vec<String>>) -> CrateMover {
+        CrateMover {
+            stacks,
+            held_crates: vec![],
+        }
+    }
+
+    fn move_crates(&mut self, instruction: &Instruction, is_version_9000: bool) {
+        for _ in 0..instruction.amount {
+            self.held_crates
+                .push(self.stacks[instruction.from - 1].pop().unwrap());
+        }
+
+        if is_version_9000 {
+            self.held_crates.reverse();
+        }
+
+        for _ in 0..instruction.amount {
+            self.stacks[instruction.to - 1].push(self.held_crates.pop().unwrap());
+        }
+    }
+
+    fn read_top_line(&mut self) -> String {
+        self.stacks
+            .iter()
+            .map(|stack| stack.last().unwrap())
+            .fold(String::new(), |acc, new| format!("{}{}", acc, new))
+    }
+}
+
+/// Crate stacks from bottom to top, read from the drawing section of the input.
+pub struct Drawing {
+    stacks: Vec<Vec<String>>,
+}
+
+impl FromSection for Drawing {
+    fn from_section(section: reader::Reader) -> Result<Self, ReaderError> {
+        Ok(Drawing {
+            stacks: parse_crates(section.lines()),
+        })
+    }
+}
+
+fn parse_crates(mut split_lines: Vec<String>) -> Vec<Vec<String>> {
+    split_lines.pop();
+
+    let lines = split_lines
+        .iter()
+        .map(|line| {
+            line.chars()
+                .collect::<Vec<char>>()
+                .chunks(4)
+                .map(|chunk| chunk[1].to_string())
+                .collect::<Vec<String>>()
+        })
+        .rev()
+        .collect::<Vec<Vec<String>>>();
+
+    // https://stackoverflow.com/questions/64498617/how-to-transpose-a-vector-of-vectors-in-rust
+    (0..lines[0].len())
+        .map(|i| {
+            lines
+                .iter()
+                .map(|inner| inner[i].clone())
+                .collect::<Vec<String>>()
+        })
+        .map(|line| {
+            line.into_iter()
+                .filter(|elem| elem != &" ".to_string())
+                .collect()
+        })
+        .collect()
+}
+
+fn part_one((drawing, instructions): &(Drawing, Vec<Instruction>)) -> String {
+    let mut crane = CrateMover::from(drawing.stacks.clone());
+
+    instructions
+        .iter()
+        .for_each(|ins| crane.move_crates(ins, true));
+
+    crane.read_top_line()
+}
+
+fn part_two((drawing, instructions): &(Drawing, Vec<Instruction>)) -> String {
+    let mut crane = CrateMover::from(drawing.stacks.clone());
+
+    instructions
+        .iter()
+        .for_each(|ins| crane.move_crates(ins, false));
+
+    crane.read_top_line()
+}
+
+pub struct Day5;
+
+impl Solution for Day5 {
+    const DAY: u8 = 5;
+    const TITLE: &'static str = "Supply Stacks";
+    const DIR: &'static str = env!("CARGO_MANIFEST_DIR");
+
+    type Input = (Drawing, Vec<Instruction>);
+    type PartOne = String;
+    type PartTwo = String;
+
+    fn parse(input: reader::Reader) -> (Drawing, Vec<Instruction>) {
+        input.sections2()
+    }
+
+    fn part_one(input: &(Drawing, Vec<Instruction>)) -> String {
+        part_one(input)
+    }
+
+    fn part_two(input: &(Drawing, Vec<Instruction>)) -> String {
+        part_two(input)
+    }
+}
+
+solution::answer_tests!(Day5);
@@ -0,0 +1,49 @@
+use solution::Solution;
+
+fn find_first_subarray_of_uniques(chars: &[char], len: usize) -> usize {
+    for i in 0..chars.len() {
+        let mut s = chars[i..i + len].to_owned();
+        s.sort();
+        s.dedup();
+
+        if s.len() == len {
+            return i + len;
+        }
+    }
+
+    0
+}
+
+fn part_one(input: &[char]) -> usize {
+    find_first_subarray_of_uniques(input, 4)
+}
+
+fn part_two(input: &[char]) -> usize {
+    find_first_subarray_of_uniques(input, 14)
+}
+
+pub struct Day6;
+
+impl Solution for Day6 {
+    const DAY: u8 = 6;
+    const TITLE: &'static str = "Tuning Trouble";
+    const DIR: &'static str = env!("CARGO_MANIFEST_DIR");
+
+    type Input = Vec<char>;
+    type PartOne = usize;
+    type PartTwo = usize;
+
+    fn parse(input: reader::Reader) -> Vec<char> {
+        input.text().chars().collect()
+    }
+
+    fn part_one(input: &Vec<char>) -> usize {
+        part_one(input)
+    }
+
+    fn part_two(input: &Vec<char>) -> usize {
+        part_two(input)
+    }
+}
+
+solution::answer_tests!(Day6);
@@ -0,0 +1,70 @@
+use solution::Solution;
+
+fn parse_folder_sizes(lines: &mut Vec<String>, res: &mut Vec<usize>) -> usize {
+    let mut current_size = 0;
+
+    loop {
+        let l = lines.pop().unwrap_or("$ cd ..".to_string());
+
+        if l.starts_with("$ cd ..") {
+            break;
+        } else if l.starts_with("$ cd") {
+            current_size += parse_folder_sizes(lines, res);
+        } else if l.as_bytes()[0].is_ascii_digit() {
+            current_size += l.split(" ").collect::<Vec<&str>>()[0]
+                .parse::<usize>()
+                .unwrap();
+        }
+    }
+
+    res.push(current_size);
+    current_size
+}
+
+/// Sizes of every folder, the outermost one last.
+fn folder_sizes(input: reader::Reader) -> Vec<usize> {
+    let mut res: Vec<usize> = vec![];
+    let mut lines = input.lines();
+    lines.reverse();
+    parse_folder_sizes(&mut lines, &mut res);
+    res
+}
+
+fn part_one(res: &[usize]) -> usize {
+    res.iter().filter(|value| value <= &&100_000).sum()
+}
+
+fn part_two(res: &[usize]) -> usize {
+    let space_needed = 30_000_000 - (70_000_000 - res.last().unwrap());
+
+    *res.iter()
+        .filter(|value| value >= &&space_needed)
+        .min()
+        .unwrap()
+}
+
+pub struct Day7;
+
+impl Solution for Day7 {
+    const DAY: u8 = 7;
+    const TITLE: &'static str = "No Space Left On Device";
+    const DIR: &'static str = env!("CARGO_MANIFEST_DIR");
+
+    type Input = Vec<usize>;
+    type PartOne = usize;
+    type PartTwo = usize;
+
+    fn parse(input: reader::Reader) -> Vec<usize> {
+        folder_sizes(input)
+    }
+
+    fn part_one(input: &Vec<usize>) -> usize {
+        part_one(input)
+    }
+
+    fn part_two(input: &Vec<usize>) -> usize {
+        part_two(input)
+    }
+}
+
+solution::answer_tests!(Day7);
@@ -0,0 +1,139 @@
+use reader::{self, Grid};
+use solution::Solution;
+use std::cmp::max;
+
+fn two_way_score(
+    lines: &Grid<usize>,
+    f: fn(Vec<usize>) -> Vec<usize>,
+) -> (Vec<Vec<usize>>, Vec<Vec<usize>>) {
+    let mut left = vec![];
+    let mut right = vec![];
+
+    lines.rows().for_each(|line| {
+        // Calculate row score (left)
+        let mut r = line.to_vec();
+        r = f(r);
+        // Calculate reversed row score (right)
+        let mut rr = line.to_vec();
+        rr.reverse();
+        rr = f(rr);
+        rr.reverse();
+
+        left.push(r);
+        right.push(rr);
+    });
+
+    (left, right)
+}
+
+/// Score maps looking up, down, left and right.
+type ScoreMaps = (
+    Vec<Vec<usize>>,
+    Vec<Vec<usize>>,
+    Vec<Vec<usize>>,
+    Vec<Vec<usize>>,
+);
+
+fn directed_score_maps(rows: &Grid<usize>, f: fn(Vec<usize>) -> Vec<usize>) -> ScoreMaps {
+    let columns = rows.transpose();
+
+    let (left, right) = two_way_score(rows, f);
+    let (down, up) = two_way_score(&columns, f);
+
+    (up, down, left, right)
+}
+
+// Score function for part 1
+fn max_heights(line: Vec<usize>) -> Vec<usize> {
+    let mut res = vec![];
+    let mut highest = 0;
+
+    line.iter().for_each(|value| {
+        res.push(highest);
+        if value > &highest {
+            highest = *value;
+        }
+    });
+
+    res
+}
+
+// Score function for part 2
+fn directed_scenic_score(line: Vec<usize>) -> Vec<usize> {
+    let mut res = vec![0];
+
+    for i in 1..line.len() {
+        let mut score = 0;
+
+        for j in (0..i).rev() {
+            score += 1;
+            if line[j] >= line[i] {
+                break;
+            }
+        }
+
+        res.push(score);
+    }
+
+    res
+}
+
+fn part_one(rows: &Grid<usize>) -> usize {
+    let (up, down, left, right) = directed_score_maps(rows, max_heights);
+
+    let mut res = 0;
+    for row in 1..rows.height() - 1 {
+        for col in 1..rows.width() - 1 {
+            let curr = rows[(row, col)];
+            if curr > left[row][col]
+                || curr > right[row][col]
+                || curr > down[col][row]
+                || curr > up[col][row]
+            {
+                res += 1;
+            }
+        }
+    }
+
+    res + 2 * rows.height() + 2 * rows.width() - 4
+}
+
+fn part_two(rows: &Grid<usize>) -> usize {
+    let (up, down, left, right) = directed_score_maps(rows, directed_scenic_score);
+
+    let mut highest = 0;
+    for row in 1..rows.height() - 1 {
+        for col in 1..rows.width() - 1 {
+            let curr = up[col][row] * down[col][row] * left[row][col] * right[row][col];
+            highest = max(highest, curr);
+        }
+    }
+
+    highest
+}
+
+pub struct Day8;
+
+impl Solution for Day8 {
+    const DAY: u8 = 8;
+    const TITLE: &'static str = "Treetop Tree House";
+    const DIR: &'static str = env!("CARGO_MANIFEST_DIR");
+
+    type Input = Grid<usize>;
+    type PartOne = usize;
+    type PartTwo = usize;
+
+    fn parse(input: reader::Reader) -> Grid<usize> {
+        input.grid::<usize>()
+    }
+
+    fn part_one(input: &Grid<usize>) -> usize {
+        part_one(input)
+    }
+
+    fn part_two(input: &Grid<usize>) -> usize {
+        part_two(input)
+    }
+}
+
+solution::answer_tests!(Day8);
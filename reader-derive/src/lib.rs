@@ -0,0 +1,350 @@
+use proc_macro::TokenStream;
+use proc_macro2::TokenStream as TokenStream2;
+use quote::{format_ident, quote};
+use syn::{parse_macro_input, Attribute, Data, DeriveInput, Fields, LitStr};
+
+/// Derive `FromStr` from a format string given in a `#[parse("..")]` attribute.
+///
+/// Placeholders in braces name the field parsed from that part of the input:
+/// - `{name}` parses a named field and `{0}` a tuple field with `FromStr`,
+/// - `{name:sep}` splits the text on `sep` and parses each item into a `Vec`,
+/// - `{_}` matches text that is ignored.
+///
+/// A field's text ends at the first occurrence of the literal text that follows it,
+/// `{{` and `}}` match literal braces. Fields without a placeholder are set to
+/// their `Default` value. Several `#[parse]` attributes can be given and are tried
+/// in order. Enum variants each take their own `#[parse]` attributes.
+///
+/// The generated implementation returns `reader::FormatError` on failure.
+/// ## Example
+/// ```rs
+/// #[derive(PuzzleParse)]
+/// #[parse("{begin}-{end}")]
+/// struct Range {
+///     begin: usize,
+///     end: usize,
+/// }
+///
+/// #[derive(PuzzleParse)]
+/// enum Instruction {
+///     #[parse("noop")]
+///     Noop,
+///     #[parse("addx {0}")]
+///     Addx(isize),
+/// }
+/// ```
+#[proc_macro_derive(PuzzleParse, attributes(parse))]
+pub fn derive_puzzle_parse(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+
+    expand(&input)
+        .unwrap_or_else(|err| err.to_compile_error())
+        .into()
+}
+
+fn expand(input: &DeriveInput) -> syn::Result<TokenStream2> {
+    let name = &input.ident;
+    let (impl_generics, ty_generics, where_clause) = input.generics.split_for_impl();
+
+    let attempts = match &input.data {
+        Data::Struct(data) => {
+            let formats = formats(&input.attrs, name)?;
+            formats
+                .iter()
+                .map(|format| attempt(format, quote!(Self), &data.fields))
+                .collect::<syn::Result<Vec<_>>>()?
+        }
+        Data::Enum(data) => {
+            let mut attempts = vec![];
+            for variant in data.variants.iter() {
+                let ident = &variant.ident;
+                for format in formats(&variant.attrs, ident)? {
+                    attempts.push(attempt(&format, quote!(Self::#ident), &variant.fields)?);
+                }
+            }
+            attempts
+        }
+        Data::Union(_) => {
+            return Err(syn::Error::new_spanned(
+                name,
+                "PuzzleParse can not be derived for unions",
+            ))
+        }
+    };
+
+    let names = (0..attempts.len()).map(|index| format_ident!("attempt_{}", index));
+    let calls = names.clone();
+
+    Ok(quote! {
+        impl #impl_generics ::core::str::FromStr for #name #ty_generics #where_clause {
+            type Err = ::reader::FormatError;
+
+            fn from_str(s: &str) -> ::core::result::Result<Self, Self::Err> {
+                #(let #names = #attempts;)*
+
+                let mut errors = ::std::vec::Vec::new();
+                #(
+                    match #calls(s) {
+                        ::core::result::Result::Ok(value) => return ::core::result::Result::Ok(value),
+                        ::core::result::Result::Err(err) => errors.push(err),
+                    }
+                )*
+
+                ::core::result::Result::Err(::reader::FormatError::from_attempts(errors))
+            }
+        }
+    })
+}
+
+/// Format strings of the `#[parse("..")]` attributes.
+fn formats(attrs: &[Attribute], owner: &syn::Ident) -> syn::Result<Vec<Format>> {
+    let formats = attrs
+        .iter()
+        .filter(|attr| attr.path().is_ident("parse"))
+        .map(|attr| {
+            let lit = attr.parse_args::<LitStr>()?;
+            Format::parse(&lit.value()).map_err(|message| syn::Error::new_spanned(&lit, message))
+        })
+        .collect::<syn::Result<Vec<_>>>()?;
+
+    if formats.is_empty() {
+        return Err(syn::Error::new_spanned(
+            owner,
+            "Missing format, add #[parse(\"..\")]",
+        ));
+    }
+
+    Ok(formats)
+}
+
+/// Closure parsing the input with a single format into `path`.
+fn attempt(format: &Format, path: TokenStream2, fields: &Fields) -> syn::Result<TokenStream2> {
+    let keys: Vec<String> = fields
+        .iter()
+        .enumerate()
+        .map(|(index, field)| match &field.ident {
+            Some(ident) => ident.to_string(),
+            None => index.to_string(),
+        })
+        .collect();
+
+    for placeholder in format.placeholders.iter() {
+        if placeholder.name != "_" && !keys.contains(&placeholder.name) {
+            return Err(syn::Error::new_spanned(
+                &path,
+                format!("Format refers to unknown field `{}`", placeholder.name),
+            ));
+        }
+    }
+
+    let values = fields.iter().zip(keys.iter()).map(|(field, key)| {
+        let ty = &field.ty;
+        let position = format
+            .placeholders
+            .iter()
+            .position(|placeholder| &placeholder.name == key);
+
+        match position {
+            Some(index) => match &format.placeholders[index].separator {
+                Some(separator) => quote! {
+                    <#ty as ::reader::format::FromList>::from_list(parts[#index], #separator, #key)?
+                },
+                None => quote! {
+                    ::reader::format::parse_field::<#ty>(parts[#index], #key)?
+                },
+            },
+            None => quote!(::core::default::Default::default()),
+        }
+    });
+
+    let construct = match fields {
+        Fields::Named(named) => {
+            let idents = named.named.iter().map(|field| &field.ident);
+            quote!(#path { #(#idents: #values),* })
+        }
+        Fields::Unnamed(_) => quote!(#path(#(#values),*)),
+        Fields::Unit => quote!(#path),
+    };
+
+    let source = &format.source;
+    let literals = &format.literals;
+
+    Ok(quote! {
+        |s: &str| -> ::core::result::Result<Self, ::reader::FormatError> {
+            let parts = ::reader::format::split_format(s, #source, &[#(#literals),*])?;
+            let _ = &parts;
+            ::core::result::Result::Ok(#construct)
+        }
+    })
+}
+
+/// Format string split into literal text and the placeholders between them.
+struct Format {
+    source: String,
+    /// Always one more than there are placeholders.
+    literals: Vec<String>,
+    placeholders: Vec<Placeholder>,
+}
+
+struct Placeholder {
+    name: String,
+    separator: Option<String>,
+}
+
+impl Format {
+    fn parse(source: &str) -> Result<Format, String> {
+        let mut literals = vec![String::new()];
+        let mut placeholders = vec![];
+        let mut chars = source.chars().peekable();
+
+        while let Some(char) = chars.next() {
+            match char {
+                '{' if chars.peek() == Some(&'{') => {
+                    chars.next();
+                    literals.last_mut().unwrap().push('{');
+                }
+                '}' if chars.peek() == Some(&'}') => {
+                    chars.next();
+                    literals.last_mut().unwrap().push('}');
+                }
+                '{' => {
+                    let mut inner = String::new();
+                    loop {
+                        match chars.next() {
+                            Some('}') => break,
+                            Some(char) => inner.push(char),
+                            None => return Err("Unclosed `{` in format".to_string()),
+                        }
+                    }
+
+                    if !placeholders.is_empty() && literals.last().unwrap().is_empty() {
+                        return Err("Placeholders must be separated by literal text".to_string());
+                    }
+
+                    let (name, separator) = match inner.split_once(':') {
+                        Some((name, separator)) if !separator.is_empty() => {
+                            (name, Some(separator.to_string()))
+                        }
+                        Some(_) => return Err("Empty list separator in format".to_string()),
+                        None => (inner.as_str(), None),
+                    };
+
+                    let valid = name == "_"
+                        || name.chars().all(|char| char.is_ascii_digit())
+                        || syn::parse_str::<syn::Ident>(name).is_ok();
+                    if name.is_empty() || !valid {
+                        return Err(format!("Invalid placeholder `{{{}}}`", inner));
+                    }
+                    if name != "_" && placeholders.iter().any(|p: &Placeholder| p.name == name) {
+                        return Err(format!("Field `{}` appears twice in format", name));
+                    }
+
+                    placeholders.push(Placeholder {
+                        name: name.to_string(),
+                        separator,
+                    });
+                    literals.push(String::new());
+                }
+                '}' => return Err("Unmatched `}` in format, use `}}`".to_string()),
+                char => literals.last_mut().unwrap().push(char),
+            }
+        }
+
+        Ok(Format {
+            source: source.to_string(),
+            literals,
+            placeholders,
+        })
+    }
+}
+
+#[cfg(test)]
+fn names(format: &Format) -> Vec<&str> {
+    format
+        .placeholders
+        .iter()
+        .map(|placeholder| placeholder.name.as_str())
+        .collect()
+}
+
+#[test]
+fn test_parse_format() {
+    let format =
+        Format::parse("Valve {name} has flow rate={flow}; tunnels lead to valves {tunnels:, }")
+            .unwrap();
+    assert_eq!(
+        format.literals,
+        vec!["Valve ", " has flow rate=", "; tunnels lead to valves ", ""]
+    );
+    assert_eq!(names(&format), vec!["name", "flow", "tunnels"]);
+    assert_eq!(format.placeholders[2].separator.as_deref(), Some(", "));
+
+    let format = Format::parse("{0}!").unwrap();
+    assert_eq!(format.literals, vec!["", "!"]);
+    assert_eq!(names(&format), vec!["0"]);
+}
+
+#[test]
+fn test_parse_format_braces() {
+    let format = Format::parse("{{{a}}} = {b}").unwrap();
+    assert_eq!(format.literals, vec!["{", "} = ", ""]);
+    assert_eq!(names(&format), vec!["a", "b"]);
+
+    assert_eq!(Format::parse("{{}}").unwrap().literals, vec!["{}"]);
+    assert!(Format::parse("{a").is_err());
+    assert!(Format::parse("a}").is_err());
+}
+
+#[test]
+fn test_parse_format_ignored() {
+    let format = Format::parse("{_} -> {_} {a}").unwrap();
+    assert_eq!(names(&format), vec!["_", "_", "a"]);
+}
+
+#[test]
+fn test_parse_format_errors() {
+    let error = |source| Format::parse(source).err().unwrap();
+    assert_eq!(
+        error("{a}{b}"),
+        "Placeholders must be separated by literal text"
+    );
+    assert_eq!(error("{a} {a}"), "Field `a` appears twice in format");
+    assert_eq!(error("{a:}"), "Empty list separator in format");
+    assert_eq!(error("{} {a b}"), "Invalid placeholder `{}`");
+    assert_eq!(error("{a b}"), "Invalid placeholder `{a b}`");
+}
+
+#[test]
+fn test_expand_errors() {
+    let error = |input: DeriveInput| expand(&input).err().unwrap().to_string();
+    assert_eq!(
+        error(syn::parse_quote! {
+            #[parse("{b}")]
+            struct Example { a: usize }
+        }),
+        "Format refers to unknown field `b`"
+    );
+    assert_eq!(
+        error(syn::parse_quote! {
+            struct Example { a: usize }
+        }),
+        "Missing format, add #[parse(\"..\")]"
+    );
+    assert_eq!(
+        error(syn::parse_quote! {
+            enum Example {
+                #[parse("a")]
+                A,
+                B,
+            }
+        }),
+        "Missing format, add #[parse(\"..\")]"
+    );
+    assert_eq!(
+        error(syn::parse_quote! {
+            #[parse("{a}{a}")]
+            struct Example { a: usize }
+        }),
+        "Placeholders must be separated by literal text"
+    );
+}
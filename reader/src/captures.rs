@@ -0,0 +1,136 @@
+use crate::BoxError;
+use regex::Match;
+use std::str::FromStr;
+
+pub use regex::Captures;
+
+/// Conversion from the capture groups of a line matched by `Reader::lines_matching`.
+///
+/// Implemented for tuples of up to eight `FromStr` types, which are parsed from
+/// the numbered groups `1..=N`. Structs can implement it with `capture` to
+/// read numbered or named groups.
+/// ## Example
+/// ```
+/// use reader::{capture, BoxError, Captures, FromCaptures};
+///
+/// struct Point {
+///     x: isize,
+///     y: isize,
+/// }
+///
+/// impl FromCaptures for Point {
+///     fn from_captures(caps: &Captures) -> Result<Self, BoxError> {
+///         Ok(Point {
+///             x: capture(caps, "x")?,
+///             y: capture(caps, "y")?,
+///         })
+///     }
+/// }
+///
+/// let input: reader::Reader = "x=1, y=-2\nx=3, y=4".parse().unwrap();
+/// let points = input.lines_matching::<Point>(r"x=(?P<x>-?\d+), y=(?P<y>-?\d+)");
+/// assert_eq!(points[1].x, 3);
+/// ```
+pub trait FromCaptures: Sized {
+    fn from_captures(caps: &Captures) -> Result<Self, BoxError>;
+}
+
+/// Capture group referred to by number or by name.
+pub trait Group: Copy + std::fmt::Display {
+    fn get<'h>(self, caps: &Captures<'h>) -> Option<Match<'h>>;
+}
+
+impl Group for usize {
+    fn get<'h>(self, caps: &Captures<'h>) -> Option<Match<'h>> {
+        caps.get(self)
+    }
+}
+
+impl Group for &str {
+    fn get<'h>(self, caps: &Captures<'h>) -> Option<Match<'h>> {
+        caps.name(self)
+    }
+}
+
+/// Parse the given capture group into the given type.
+///
+/// Returns an error if the group did not take part in the match or can not be parsed.
+pub fn capture<T>(caps: &Captures, group: impl Group) -> Result<T, BoxError>
+where
+    T: FromStr,
+    T::Err: Into<BoxError>,
+{
+    let text = group
+        .get(caps)
+        .ok_or_else(|| format!("Capture group {} did not match.", group))?
+        .as_str();
+
+    text.parse::<T>().map_err(|err| {
+        format!(
+            "Capture group {} {:?} is not a valid \"{}\": {}",
+            group,
+            text,
+            std::any::type_name::<T>(),
+            err.into()
+        )
+        .into()
+    })
+}
+
+macro_rules! tuple_from_captures {
+    ($($name:ident $group:literal),+) => {
+        impl<$($name),+> FromCaptures for ($($name,)+)
+        where
+            $($name: FromStr, $name::Err: Into<BoxError>,)+
+        {
+            fn from_captures(caps: &Captures) -> Result<Self, BoxError> {
+                Ok(($(capture::<$name>(caps, $group as usize)?,)+))
+            }
+        }
+    };
+}
+
+tuple_from_captures!(A 1);
+tuple_from_captures!(A 1, B 2);
+tuple_from_captures!(A 1, B 2, C 3);
+tuple_from_captures!(A 1, B 2, C 3, D 4);
+tuple_from_captures!(A 1, B 2, C 3, D 4, E 5);
+tuple_from_captures!(A 1, B 2, C 3, D 4, E 5, F 6);
+tuple_from_captures!(A 1, B 2, C 3, D 4, E 5, F 6, G 7);
+tuple_from_captures!(A 1, B 2, C 3, D 4, E 5, F 6, G 7, H 8);
+
+#[test]
+fn test_no_match() {
+    use crate::{Reader, ReaderError};
+
+    let input = Reader::from_embedded("x=1, y=2\nx=3,y=4\n", "input.txt");
+    match input.try_lines_matching::<(isize, isize)>(r"x=(-?\d+), y=(-?\d+)") {
+        Err(ReaderError::NoMatch { pattern, location }) => {
+            assert_eq!(pattern, r"x=(-?\d+), y=(-?\d+)");
+            assert_eq!((location.line, location.column), (2, 1));
+            assert_eq!(location.excerpt, vec!["x=3,y=4"]);
+        }
+        other => panic!("Expected a line that does not match, got {:?}", other),
+    }
+}
+
+#[test]
+fn test_invalid_pattern() {
+    use crate::{Reader, ReaderError};
+
+    let input = Reader::from("x=1");
+    match input.try_lines_matching::<(isize,)>(r"x=(\d+") {
+        Err(ReaderError::Pattern { pattern, .. }) => assert_eq!(pattern, r"x=(\d+"),
+        other => panic!("Expected an invalid pattern, got {:?}", other),
+    }
+}
+
+#[test]
+fn test_capture_parse_error() {
+    let input = crate::Reader::from("x=1\nx=a");
+    let err = input
+        .try_lines_matching::<(usize,)>(r"x=(\w+)")
+        .unwrap_err();
+    assert_eq!(err.location().unwrap().line, 2);
+    assert!(err.to_string().contains("Capture group 1 \"a\""));
+}
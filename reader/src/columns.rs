@@ -0,0 +1,216 @@
+use crate::BoxError;
+use std::{ops::Deref, str::FromStr};
+
+/// Last column of `Reader::columns` that collects every remaining field.
+/// ## Example
+/// ```
+/// use reader::Rest;
+///
+/// let input: reader::Reader = "AA 13 DD II\nBB 0 CC".parse().unwrap();
+/// let valves = input.columns::<(String, usize, Rest<String>)>(" ");
+/// assert_eq!(*valves[0].2, vec!["DD", "II"]);
+/// ```
+///
+/// Only the last column can be a `Rest`, other positions fail to compile:
+/// ```compile_fail
+/// use reader::Rest;
+///
+/// let input: reader::Reader = "AA DD II 13".parse().unwrap();
+/// input.columns::<(String, Rest<String>, usize)>(" ");
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Default)]
+pub struct Rest<T>(pub Vec<T>);
+
+impl<T> Rest<T> {
+    pub fn into_inner(self) -> Vec<T> {
+        self.0
+    }
+}
+
+impl<T> Deref for Rest<T> {
+    type Target = Vec<T>;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+/// Single column of a `FromColumns` tuple, parsed from one field or, for
+/// `Rest`, from all remaining fields.
+pub trait Column: Sized {
+    /// Whether the column takes every remaining field.
+    const REST: bool = false;
+
+    /// Parse the column from its fields. On failure returns the index of the
+    /// offending field within `fields` and its error.
+    fn from_fields(fields: &[&str]) -> Result<Self, ColumnError>;
+}
+
+impl<T> Column for T
+where
+    T: FromStr,
+    T::Err: Into<BoxError>,
+{
+    fn from_fields(fields: &[&str]) -> Result<Self, ColumnError> {
+        parse_field(0, fields[0])
+    }
+}
+
+impl<T> Column for Rest<T>
+where
+    T: FromStr,
+    T::Err: Into<BoxError>,
+{
+    const REST: bool = true;
+
+    fn from_fields(fields: &[&str]) -> Result<Self, ColumnError> {
+        fields
+            .iter()
+            .enumerate()
+            .map(|(index, field)| parse_field(index, field))
+            .collect::<Result<Vec<T>, ColumnError>>()
+            .map(Rest)
+    }
+}
+
+fn parse_field<T>(index: usize, field: &str) -> Result<T, ColumnError>
+where
+    T: FromStr,
+    T::Err: Into<BoxError>,
+{
+    field.parse::<T>().map_err(|err| ColumnError::Parse {
+        field: index,
+        type_name: std::any::type_name::<T>(),
+        source: err.into(),
+    })
+}
+
+/// Error of a single line in `FromColumns`, turned into a `ReaderError` with
+/// the location of the line or field by the `Reader`.
+#[derive(Debug)]
+pub enum ColumnError {
+    /// The line has a different number of fields than there are columns.
+    /// `rest` is set when the last column takes any number of fields.
+    Count { expected: usize, rest: bool },
+    /// The field with the given index could not be parsed.
+    Parse {
+        field: usize,
+        type_name: &'static str,
+        source: BoxError,
+    },
+}
+
+/// Conversion from the fields of a line, see `Reader::columns`.
+///
+/// Implemented for tuples of up to eight `Column` types. Each column takes one
+/// field, except a `Rest` in the last position which takes the remaining ones.
+/// A `Rest` in any other position is rejected at compile time.
+pub trait FromColumns: Sized {
+    fn from_columns(fields: &[&str]) -> Result<Self, ColumnError>;
+}
+
+macro_rules! tuple_from_columns {
+    ($count:literal; $($name:ident $index:tt),+; $last:ident $last_index:tt) => {
+        impl<$($name,)+ $last> FromColumns for ($($name,)+ $last,)
+        where
+            $($name: Column,)+
+            $last: Column,
+        {
+            fn from_columns(fields: &[&str]) -> Result<Self, ColumnError> {
+                const { assert!(!($($name::REST)||+), "Only the last column can be a Rest.") };
+
+                let count_ok = if $last::REST {
+                    fields.len() >= $count - 1
+                } else {
+                    fields.len() == $count
+                };
+                if !count_ok {
+                    return Err(ColumnError::Count {
+                        expected: $count,
+                        rest: $last::REST,
+                    });
+                }
+
+                Ok((
+                    $(offset($index, $name::from_fields(&fields[$index..=$index]))?,)+
+                    offset($last_index, $last::from_fields(&fields[$last_index..]))?,
+                ))
+            }
+        }
+    };
+}
+
+/// Shift the field index of an error by the index of the column's first field.
+fn offset<T>(start: usize, result: Result<T, ColumnError>) -> Result<T, ColumnError> {
+    result.map_err(|err| match err {
+        ColumnError::Parse {
+            field,
+            type_name,
+            source,
+        } => ColumnError::Parse {
+            field: start + field,
+            type_name,
+            source,
+        },
+        err => err,
+    })
+}
+
+impl<A> FromColumns for (A,)
+where
+    A: Column,
+{
+    fn from_columns(fields: &[&str]) -> Result<Self, ColumnError> {
+        if fields.len() != 1 && !A::REST {
+            return Err(ColumnError::Count {
+                expected: 1,
+                rest: false,
+            });
+        }
+
+        Ok((A::from_fields(fields)?,))
+    }
+}
+
+tuple_from_columns!(2; A 0; B 1);
+tuple_from_columns!(3; A 0, B 1; C 2);
+tuple_from_columns!(4; A 0, B 1, C 2; D 3);
+tuple_from_columns!(5; A 0, B 1, C 2, D 3; E 4);
+tuple_from_columns!(6; A 0, B 1, C 2, D 3, E 4; F 5);
+tuple_from_columns!(7; A 0, B 1, C 2, D 3, E 4, F 5; G 6);
+tuple_from_columns!(8; A 0, B 1, C 2, D 3, E 4, F 5, G 6; H 7);
+
+#[test]
+fn test_column_count() {
+    use crate::{Reader, ReaderError};
+
+    let input = Reader::from_embedded("R 4\nU\n", "input.txt");
+    match input.try_columns::<(char, usize)>(" ") {
+        Err(ReaderError::Columns {
+            expected,
+            rest,
+            found,
+            location,
+        }) => {
+            assert_eq!((expected, rest, found), (2, false, 1));
+            assert_eq!((location.line, location.column), (2, 1));
+        }
+        other => panic!("Expected a wrong number of fields, got {:?}", other),
+    }
+
+    let input = Reader::from("AA");
+    let err = input
+        .try_columns::<(String, usize, Rest<String>)>(" ")
+        .unwrap_err();
+    assert!(err
+        .to_string()
+        .starts_with("Line has 1 fields but at least 2 were expected."));
+}
+
+#[test]
+fn test_empty_delimiter() {
+    use crate::{Reader, ReaderError};
+
+    let result = Reader::from("R 4").try_columns::<(char, usize)>("");
+    assert!(matches!(result, Err(ReaderError::EmptyDelimiter)));
+}
@@ -0,0 +1,189 @@
+use crate::ReaderError;
+use std::{
+    fs::File,
+    io::{self, BufRead, BufReader, Read},
+};
+
+/// Compression formats recognized from the first bytes of an input file.
+const FORMATS: [(&str, &[u8]); 2] = [("gzip", &[0x1f, 0x8b]), ("zstd", &[0x28, 0xb5, 0x2f, 0xfd])];
+
+/// Input file opened by `reader::open` and `reader::stream`.
+///
+/// Files compressed with gzip or zstd are decompressed while they are read when
+/// the `compression` feature is enabled. The format is detected from the magic
+/// bytes at the start of the file, not from its extension.
+pub struct InputFile {
+    inner: Inner,
+}
+
+enum Inner {
+    Plain(BufReader<File>),
+    #[cfg(feature = "compression")]
+    Gzip(BufReader<flate2::bufread::MultiGzDecoder<BufReader<File>>>),
+    #[cfg(feature = "compression")]
+    Zstd(BufReader<zstd::stream::read::Decoder<'static, BufReader<File>>>),
+}
+
+impl InputFile {
+    /// Open the file at the given, already resolved, path.
+    ///
+    /// Returns `ReaderError::Compressed` for compressed files when the
+    /// `compression` feature is disabled.
+    pub(crate) fn open(path: &str) -> Result<Self, ReaderError> {
+        let io_error = |source| ReaderError::Io {
+            path: path.to_string(),
+            source,
+        };
+
+        let mut file = BufReader::new(File::open(path).map_err(io_error)?);
+        let start = file.fill_buf().map_err(io_error)?;
+        let format = FORMATS
+            .iter()
+            .find(|(_, magic)| start.starts_with(magic))
+            .map(|(format, _)| *format);
+
+        let inner = match format {
+            None => Inner::Plain(file),
+            #[cfg(feature = "compression")]
+            Some("gzip") => Inner::Gzip(BufReader::new(flate2::bufread::MultiGzDecoder::new(file))),
+            #[cfg(feature = "compression")]
+            Some(_) => Inner::Zstd(BufReader::new(
+                zstd::stream::read::Decoder::with_buffer(file).map_err(io_error)?,
+            )),
+            #[cfg(not(feature = "compression"))]
+            Some(format) => {
+                return Err(ReaderError::Compressed {
+                    path: path.to_string(),
+                    format,
+                })
+            }
+        };
+
+        Ok(InputFile { inner })
+    }
+}
+
+impl Read for InputFile {
+    fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+        match &mut self.inner {
+            Inner::Plain(inner) => inner.read(buf),
+            #[cfg(feature = "compression")]
+            Inner::Gzip(inner) => inner.read(buf),
+            #[cfg(feature = "compression")]
+            Inner::Zstd(inner) => inner.read(buf),
+        }
+    }
+}
+
+impl BufRead for InputFile {
+    fn fill_buf(&mut self) -> io::Result<&[u8]> {
+        match &mut self.inner {
+            Inner::Plain(inner) => inner.fill_buf(),
+            #[cfg(feature = "compression")]
+            Inner::Gzip(inner) => inner.fill_buf(),
+            #[cfg(feature = "compression")]
+            Inner::Zstd(inner) => inner.fill_buf(),
+        }
+    }
+
+    fn consume(&mut self, amount: usize) {
+        match &mut self.inner {
+            Inner::Plain(inner) => inner.consume(amount),
+            #[cfg(feature = "compression")]
+            Inner::Gzip(inner) => inner.consume(amount),
+            #[cfg(feature = "compression")]
+            Inner::Zstd(inner) => inner.consume(amount),
+        }
+    }
+}
+
+/// Write a fixture with the given content to a temporary file.
+#[cfg(test)]
+fn fixture(name: &str, content: &[u8]) -> String {
+    let path = std::env::temp_dir().join(format!("reader-{}-{}", std::process::id(), name));
+    std::fs::write(&path, content).unwrap();
+    path.to_string_lossy().to_string()
+}
+
+#[cfg(test)]
+fn gzip(text: &str) -> Vec<u8> {
+    use std::io::Write;
+
+    let mut encoder = flate2::write::GzEncoder::new(vec![], flate2::Compression::default());
+    encoder.write_all(text.as_bytes()).unwrap();
+    encoder.finish().unwrap()
+}
+
+#[cfg(test)]
+fn zstd(text: &str) -> Vec<u8> {
+    zstd::encode_all(text.as_bytes(), 0).unwrap()
+}
+
+#[cfg(feature = "compression")]
+#[test]
+fn test_gzip() {
+    let path = fixture("input.txt.gz", &gzip("1\n2\n\n3\n"));
+    assert_eq!(crate::open(&path).lines().len(), 4);
+    assert_eq!(
+        crate::open(&path).blocks_of::<usize>(),
+        vec![vec![1, 2], vec![3]]
+    );
+    assert_eq!(
+        crate::stream(&path)
+            .split_on_empty_line()
+            .collect::<Vec<String>>(),
+        vec!["1\n2", "3"]
+    );
+    std::fs::remove_file(&path).unwrap();
+}
+
+#[cfg(feature = "compression")]
+#[test]
+fn test_gzip_members() {
+    let mut content = gzip("1,2,");
+    content.extend(gzip("3\n"));
+    let path = fixture("members.gz", &content);
+    assert_eq!(
+        crate::open(&path).split_line_into::<usize>(","),
+        vec![1, 2, 3]
+    );
+    std::fs::remove_file(&path).unwrap();
+}
+
+#[cfg(feature = "compression")]
+#[test]
+fn test_zstd() {
+    // Compressed files are detected from their content, not their extension.
+    let path = fixture("input.txt", &zstd("1,2,3\n"));
+    assert_eq!(
+        crate::open(&path).split_line_into::<usize>(","),
+        vec![1, 2, 3]
+    );
+    assert_eq!(
+        crate::stream(&path)
+            .split_line_into::<usize>(",")
+            .collect::<Vec<usize>>(),
+        vec![1, 2, 3]
+    );
+    std::fs::remove_file(&path).unwrap();
+}
+
+#[cfg(not(feature = "compression"))]
+#[test]
+fn test_compressed_without_feature() {
+    for (name, content, expected) in [
+        ("disabled.gz", gzip("1\n"), "gzip"),
+        ("disabled.zst", zstd("1\n"), "zstd"),
+    ] {
+        let path = fixture(name, &content);
+        assert!(matches!(
+            crate::try_open(&path),
+            Err(ReaderError::Compressed { format, .. }) if format == expected
+        ));
+        assert!(matches!(
+            crate::try_stream(&path),
+            Err(ReaderError::Compressed { format, .. }) if format == expected
+        ));
+        std::fs::remove_file(&path).unwrap();
+    }
+}
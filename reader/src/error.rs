@@ -0,0 +1,296 @@
+use crate::Location;
+use std::{error::Error, fmt, path::PathBuf, str::FromStr};
+
+/// Boxed error produced by a `FromStr` implementation.
+pub type BoxError = Box<dyn Error + Send + Sync>;
+
+/// Wrapper that lets the panicking methods of `Reader` parse types whose error
+/// only implements `Debug`, by turning the error into its `Debug` text.
+#[derive(Debug)]
+pub(crate) struct Lenient<T>(pub T);
+
+impl<T> FromStr for Lenient<T>
+where
+    T: FromStr,
+    T::Err: fmt::Debug,
+{
+    type Err = DebugError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        s.parse().map(Lenient).map_err(DebugError::new)
+    }
+}
+
+/// `Debug` text of an error that does not implement `Error`.
+#[derive(Debug)]
+pub(crate) struct DebugError(pub String);
+
+impl DebugError {
+    pub(crate) fn new(err: impl fmt::Debug) -> Self {
+        DebugError(format!("{:?}", err))
+    }
+}
+
+impl fmt::Display for DebugError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
+impl Error for DebugError {}
+
+/// Name of `T` for error messages, looking through `Lenient`.
+fn type_name<T>() -> &'static str {
+    let name = std::any::type_name::<T>();
+    name.strip_prefix(concat!(module_path!(), "::Lenient<"))
+        .and_then(|name| name.strip_suffix('>'))
+        .unwrap_or(name)
+}
+
+/// Error returned by the fallible `try_*` methods of `Reader`.
+#[derive(Debug)]
+pub enum ReaderError {
+    /// The input file was not found in any of the searched locations.
+    NotFound {
+        path: String,
+        /// Every location that was tried, in search order.
+        tried: Vec<PathBuf>,
+    },
+    /// The input could not be read.
+    Io {
+        path: String,
+        source: std::io::Error,
+    },
+    /// Part of the input could not be parsed into the requested type.
+    Parse {
+        /// The text that failed to parse.
+        text: String,
+        /// Name of the type the text was parsed into.
+        type_name: &'static str,
+        /// Error returned by the `FromStr` implementation.
+        source: BoxError,
+        /// Where the text is located in the input.
+        location: Box<Location>,
+    },
+    /// The input file is compressed but the `compression` feature is disabled.
+    Compressed {
+        path: String,
+        /// Name of the detected compression format.
+        format: &'static str,
+    },
+    /// The input contained nothing to parse. Only the fallible constructors
+    /// (`try_*`, `str::parse` and `piped_stdin`) return this, the panicking ones
+    /// and `From` give an empty `Reader` instead.
+    Empty { path: String },
+    /// The regular expression given to `lines_matching` is invalid.
+    Pattern {
+        pattern: String,
+        source: regex::Error,
+    },
+    /// A line did not match the regular expression given to `lines_matching`.
+    NoMatch {
+        pattern: String,
+        /// The line that did not match.
+        location: Box<Location>,
+    },
+    /// A grid row had a different number of cells than the first row.
+    Ragged {
+        expected: usize,
+        found: usize,
+        /// The offending row.
+        location: Box<Location>,
+    },
+    /// A line did not contain the number of integers asked from `ints_per_line_array`.
+    IntCount {
+        expected: usize,
+        found: usize,
+        /// The offending line.
+        location: Box<Location>,
+    },
+    /// The input did not divide into the number of sections asked from `sections2` or `sections3`.
+    SectionCount {
+        path: Option<String>,
+        expected: usize,
+        found: usize,
+    },
+    /// A block had a different number of lines than asked from `pairs_of`.
+    BlockSize {
+        expected: usize,
+        found: usize,
+        /// The offending block.
+        location: Box<Location>,
+    },
+    /// A line had a different number of fields than asked from `columns`.
+    Columns {
+        expected: usize,
+        /// Whether the last column accepts any number of fields, making
+        /// `expected - 1` the minimum.
+        rest: bool,
+        found: usize,
+        /// The offending line.
+        location: Box<Location>,
+    },
+    /// The delimiter given to `columns` is empty.
+    EmptyDelimiter,
+    /// Strict normalization found content it would have to change, see `Normalize`.
+    Malformed {
+        issue: &'static str,
+        location: Box<Location>,
+    },
+    /// A section of the input could not be converted with `FromSection`.
+    Section {
+        /// 1-based index of the section.
+        index: usize,
+        /// Name of the type the section was parsed into.
+        type_name: &'static str,
+        source: Box<ReaderError>,
+    },
+}
+
+impl ReaderError {
+    pub(crate) fn parse<T>(text: &str, source: impl Into<BoxError>, location: Location) -> Self {
+        ReaderError::Parse {
+            text: text.to_string(),
+            type_name: type_name::<T>(),
+            source: source.into(),
+            location: Box::new(location),
+        }
+    }
+
+    /// Location of the offending part of the input, if known.
+    pub fn location(&self) -> Option<&Location> {
+        match self {
+            ReaderError::Parse { location, .. }
+            | ReaderError::NoMatch { location, .. }
+            | ReaderError::Ragged { location, .. }
+            | ReaderError::IntCount { location, .. }
+            | ReaderError::Columns { location, .. }
+            | ReaderError::BlockSize { location, .. }
+            | ReaderError::Malformed { location, .. } => Some(location),
+            ReaderError::Section { source, .. } => source.location(),
+            _ => None,
+        }
+    }
+}
+
+impl fmt::Display for ReaderError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ReaderError::NotFound { path, tried } => {
+                write!(f, "Unable to find \"{}\", tried:", path)?;
+                for candidate in tried {
+                    write!(f, "\n\t{}", candidate.display())?;
+                }
+                Ok(())
+            }
+            ReaderError::Io { path, source } => {
+                write!(f, "Unable to read \"{}\": {}", path, source)
+            }
+            ReaderError::Parse {
+                text,
+                type_name,
+                source,
+                location,
+            } => {
+                if text.contains('\n') {
+                    write!(f, "Unable to parse block")?;
+                } else {
+                    write!(f, "Unable to parse {:?}", text)?;
+                }
+                write!(
+                    f,
+                    " into the given type \"{}\": {}\n{}",
+                    type_name, source, location
+                )
+            }
+            ReaderError::Compressed { path, format } => write!(
+                f,
+                "Input \"{}\" is {} compressed, enable the \"compression\" feature of reader to read it.",
+                path, format
+            ),
+            ReaderError::Empty { path } => write!(f, "Input \"{}\" is empty.", path),
+            ReaderError::Pattern { pattern, source } => {
+                write!(f, "Invalid pattern {:?}: {}", pattern, source)
+            }
+            ReaderError::NoMatch { pattern, location } => write!(
+                f,
+                "Line does not match the pattern {:?}.\n{}",
+                pattern, location
+            ),
+            ReaderError::Ragged {
+                expected,
+                found,
+                location,
+            } => write!(
+                f,
+                "Grid row has {} cells but the first row has {}.\n{}",
+                found, expected, location
+            ),
+            ReaderError::IntCount {
+                expected,
+                found,
+                location,
+            } => write!(
+                f,
+                "Line contains {} integers but {} were expected.\n{}",
+                found, expected, location
+            ),
+            ReaderError::SectionCount {
+                path,
+                expected,
+                found,
+            } => write!(
+                f,
+                "Input \"{}\" has {} sections separated by empty lines but {} were expected.",
+                path.as_deref().unwrap_or("<input>"),
+                found,
+                expected
+            ),
+            ReaderError::BlockSize {
+                expected,
+                found,
+                location,
+            } => write!(
+                f,
+                "Block has {} lines but {} were expected.\n{}",
+                found, expected, location
+            ),
+            ReaderError::Columns {
+                expected,
+                rest,
+                found,
+                location,
+            } => {
+                if *rest {
+                    write!(f, "Line has {} fields but at least {}", found, expected - 1)?;
+                } else {
+                    write!(f, "Line has {} fields but {}", found, expected)?;
+                }
+                write!(f, " were expected.\n{}", location)
+            }
+            ReaderError::EmptyDelimiter => write!(f, "Column delimiter must not be empty."),
+            ReaderError::Malformed { issue, location } => write!(f, "{}.\n{}", issue, location),
+            ReaderError::Section {
+                index,
+                type_name,
+                source,
+            } => write!(
+                f,
+                "Unable to parse section {} into the given type \"{}\": {}",
+                index, type_name, source
+            ),
+        }
+    }
+}
+
+impl Error for ReaderError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            ReaderError::Io { source, .. } => Some(source),
+            ReaderError::Parse { source, .. } => Some(source.as_ref()),
+            ReaderError::Pattern { source, .. } => Some(source),
+            ReaderError::Section { source, .. } => Some(source.as_ref()),
+            _ => None,
+        }
+    }
+}
@@ -0,0 +1,211 @@
+//! Runtime support for `#[derive(PuzzleParse)]`.
+
+use crate::BoxError;
+use std::{error::Error, fmt, str::FromStr};
+
+/// Error returned by `FromStr` implementations generated with `#[derive(PuzzleParse)]`.
+#[derive(Debug)]
+pub enum FormatError {
+    /// The input does not contain the literal text of the format.
+    Literal {
+        format: &'static str,
+        /// The missing literal text. Empty for the end of input.
+        expected: &'static str,
+        /// Input remaining where the literal text was expected.
+        found: String,
+    },
+    /// The text matched by a placeholder could not be parsed.
+    Field {
+        field: &'static str,
+        text: String,
+        source: BoxError,
+    },
+    /// The input matched none of the formats. Holds the error for each format in order.
+    NoMatch(Vec<FormatError>),
+}
+
+impl FormatError {
+    /// Combine the errors of every attempted format.
+    pub fn from_attempts(mut errors: Vec<FormatError>) -> Self {
+        if errors.len() == 1 {
+            errors.pop().unwrap()
+        } else {
+            FormatError::NoMatch(errors)
+        }
+    }
+}
+
+impl fmt::Display for FormatError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            FormatError::Literal {
+                format,
+                expected,
+                found,
+            } => {
+                if expected.is_empty() {
+                    write!(f, "Expected end of input")?;
+                } else {
+                    write!(f, "Expected {:?}", expected)?;
+                }
+                write!(f, " at {:?} for format {:?}", found, format)
+            }
+            FormatError::Field {
+                field,
+                text,
+                source,
+            } => write!(
+                f,
+                "Unable to parse field `{}` from {:?}: {}",
+                field, text, source
+            ),
+            FormatError::NoMatch(errors) => {
+                write!(f, "Input matches none of the formats:")?;
+                for err in errors {
+                    write!(f, "\n\t{}", err)?;
+                }
+                Ok(())
+            }
+        }
+    }
+}
+
+impl Error for FormatError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            FormatError::Field { source, .. } => Some(source.as_ref()),
+            _ => None,
+        }
+    }
+}
+
+/// Split the input into the text matched by each placeholder of a format.
+///
+/// `literals` is the literal text around the placeholders, one more than there
+/// are placeholders. Each placeholder ends at the first occurrence of the following
+/// literal, except for the last one which ends at the final literal.
+pub fn split_format<'a>(
+    input: &'a str,
+    format: &'static str,
+    literals: &[&'static str],
+) -> Result<Vec<&'a str>, FormatError> {
+    let missing = |expected: &'static str, found: &str| FormatError::Literal {
+        format,
+        expected,
+        found: found.to_string(),
+    };
+
+    let mut rest = input
+        .strip_prefix(literals[0])
+        .ok_or_else(|| missing(literals[0], input))?;
+    let mut parts = vec![];
+
+    for (index, literal) in literals.iter().enumerate().skip(1) {
+        if index == literals.len() - 1 {
+            parts.push(
+                rest.strip_suffix(literal)
+                    .ok_or_else(|| missing(literal, rest))?,
+            );
+            rest = "";
+        } else {
+            let end = rest.find(literal).ok_or_else(|| missing(literal, rest))?;
+            parts.push(&rest[..end]);
+            rest = &rest[end + literal.len()..];
+        }
+    }
+
+    if !rest.is_empty() {
+        return Err(missing("", rest));
+    }
+
+    Ok(parts)
+}
+
+/// Parse the text matched by a `{field}` placeholder.
+pub fn parse_field<T>(text: &str, field: &'static str) -> Result<T, FormatError>
+where
+    T: FromStr,
+    T::Err: Into<BoxError>,
+{
+    text.parse::<T>().map_err(|err| FormatError::Field {
+        field,
+        text: text.to_string(),
+        source: err.into(),
+    })
+}
+
+/// Types parsed from the text matched by a `{field:separator}` placeholder.
+pub trait FromList: Sized {
+    fn from_list(text: &str, separator: &str, field: &'static str) -> Result<Self, FormatError>;
+}
+
+impl<T> FromList for Vec<T>
+where
+    T: FromStr,
+    T::Err: Into<BoxError>,
+{
+    fn from_list(text: &str, separator: &str, field: &'static str) -> Result<Self, FormatError> {
+        text.split(separator)
+            .map(|item| parse_field(item, field))
+            .collect()
+    }
+}
+
+#[test]
+fn test_split_format() {
+    let literals = ["Valve ", " has flow rate=", ""];
+    assert_eq!(
+        split_format("Valve AA has flow rate=0", "", &literals).unwrap(),
+        vec!["AA", "0"]
+    );
+
+    // The last placeholder ends at the final literal, not at its first occurrence.
+    assert_eq!(
+        split_format("(1, (2, 3))", "", &["(", ", ", ")"]).unwrap(),
+        vec!["1", "(2, 3)"]
+    );
+    assert_eq!(split_format("{}", "", &["{}"]).unwrap(), Vec::<&str>::new());
+}
+
+#[test]
+fn test_split_format_errors() {
+    let format = "{a}-{b}!";
+    let literals = ["", "-", "!"];
+    let err = split_format("1-2", format, &literals).unwrap_err();
+    assert_eq!(
+        err.to_string(),
+        "Expected \"!\" at \"2\" for format \"{a}-{b}!\""
+    );
+
+    let err = split_format("1", format, &literals).unwrap_err();
+    assert_eq!(
+        err.to_string(),
+        "Expected \"-\" at \"1\" for format \"{a}-{b}!\""
+    );
+
+    let err = split_format("noop 1", "noop", &["noop"]).unwrap_err();
+    assert_eq!(
+        err.to_string(),
+        "Expected end of input at \" 1\" for format \"noop\""
+    );
+}
+
+#[test]
+fn test_no_match() {
+    let attempts = vec![
+        split_format("addx", "noop", &["noop"]).unwrap_err(),
+        parse_field::<isize>("x", "0").unwrap_err(),
+    ];
+    assert_eq!(
+        FormatError::from_attempts(attempts).to_string(),
+        "Input matches none of the formats:\n\
+         \tExpected \"noop\" at \"addx\" for format \"noop\"\n\
+         \tUnable to parse field `0` from \"x\": invalid digit found in string"
+    );
+
+    let single = vec![split_format("addx", "noop", &["noop"]).unwrap_err()];
+    assert!(matches!(
+        FormatError::from_attempts(single),
+        FormatError::Literal { .. }
+    ));
+}
@@ -0,0 +1,273 @@
+use std::{
+    fmt,
+    ops::{Index, IndexMut},
+};
+
+/// Position in a `Grid` as `(row, column)`.
+pub type Position = (usize, usize);
+
+const OFFSETS_4: [(isize, isize); 4] = [(-1, 0), (1, 0), (0, -1), (0, 1)];
+const OFFSETS_8: [(isize, isize); 8] = [
+    (-1, -1),
+    (-1, 0),
+    (-1, 1),
+    (0, -1),
+    (0, 1),
+    (1, -1),
+    (1, 0),
+    (1, 1),
+];
+
+/// Dense two dimensional grid stored in row-major order.
+///
+/// Use `Reader::grid` to parse a character map into a `Grid`.
+/// ## Example
+/// ```
+/// let grid = "S.#\n..E".parse::<reader::Reader>().unwrap().grid::<char>();
+///
+/// assert_eq!((grid.height(), grid.width()), (2, 3));
+/// assert_eq!(grid.find(&'E'), Some((1, 2)));
+/// assert_eq!(grid[(0, 2)], '#');
+/// assert_eq!(grid.get((2, 0)), None);
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Grid<T> {
+    cells: Vec<T>,
+    width: usize,
+    height: usize,
+}
+
+impl<T> Grid<T> {
+    /// Create a grid from cells in row-major order.
+    ///
+    /// Panics if the number of cells is not `width * height`.
+    pub fn new(width: usize, height: usize, cells: Vec<T>) -> Self {
+        assert_eq!(
+            cells.len(),
+            width * height,
+            "Grid of {}x{} needs {} cells.",
+            width,
+            height,
+            width * height
+        );
+
+        Grid {
+            cells,
+            width,
+            height,
+        }
+    }
+
+    /// Create a grid filled with the given value.
+    pub fn filled(width: usize, height: usize, value: T) -> Self
+    where
+        T: Clone,
+    {
+        Grid::new(width, height, vec![value; width * height])
+    }
+
+    /// Number of columns.
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    /// Number of rows.
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    /// Whether the position is inside the grid.
+    pub fn contains(&self, (row, col): Position) -> bool {
+        row < self.height && col < self.width
+    }
+
+    /// Cell at the given position, or `None` if it is outside the grid.
+    pub fn get(&self, pos: Position) -> Option<&T> {
+        self.contains(pos)
+            .then(|| &self.cells[pos.0 * self.width + pos.1])
+    }
+
+    /// Mutable cell at the given position, or `None` if it is outside the grid.
+    pub fn get_mut(&mut self, pos: Position) -> Option<&mut T> {
+        if self.contains(pos) {
+            Some(&mut self.cells[pos.0 * self.width + pos.1])
+        } else {
+            None
+        }
+    }
+
+    /// Every position of the grid in row-major order.
+    pub fn positions(&self) -> impl Iterator<Item = Position> {
+        let width = self.width;
+        (0..self.height).flat_map(move |row| (0..width).map(move |col| (row, col)))
+    }
+
+    /// Positions directly above, below, left and right of the given position
+    /// that are inside the grid.
+    pub fn neighbours4(&self, pos: Position) -> impl Iterator<Item = Position> + '_ {
+        self.offset_positions(pos, &OFFSETS_4)
+    }
+
+    /// Positions around the given position, including diagonals, that are inside the grid.
+    pub fn neighbours8(&self, pos: Position) -> impl Iterator<Item = Position> + '_ {
+        self.offset_positions(pos, &OFFSETS_8)
+    }
+
+    fn offset_positions<'a>(
+        &'a self,
+        (row, col): Position,
+        offsets: &'a [(isize, isize)],
+    ) -> impl Iterator<Item = Position> + 'a {
+        offsets.iter().filter_map(move |(dr, dc)| {
+            let pos = (row.checked_add_signed(*dr)?, col.checked_add_signed(*dc)?);
+            self.contains(pos).then_some(pos)
+        })
+    }
+
+    /// Row at the given index as a slice.
+    ///
+    /// Panics if the row is outside the grid.
+    pub fn row(&self, row: usize) -> &[T] {
+        assert!(row < self.height, "Row {} is outside the grid.", row);
+        &self.cells[row * self.width..(row + 1) * self.width]
+    }
+
+    /// Rows from top to bottom.
+    pub fn rows(&self) -> impl Iterator<Item = &[T]> {
+        // `chunks` panics on zero sized chunks, so empty rows are produced separately.
+        let empty: &[T] = &[];
+        let rows = self.cells.chunks(self.width.max(1));
+        let empties = std::iter::repeat_n(empty, if self.width == 0 { self.height } else { 0 });
+        rows.chain(empties)
+    }
+
+    /// Column at the given index from top to bottom.
+    ///
+    /// Panics if the column is outside the grid.
+    pub fn column(&self, col: usize) -> impl Iterator<Item = &T> {
+        assert!(col < self.width, "Column {} is outside the grid.", col);
+        self.cells.iter().skip(col).step_by(self.width)
+    }
+
+    /// Columns from left to right.
+    pub fn columns(&self) -> impl Iterator<Item = impl Iterator<Item = &T>> {
+        (0..self.width).map(|col| self.column(col))
+    }
+
+    /// Cells in row-major order.
+    pub fn iter(&self) -> impl Iterator<Item = &T> {
+        self.cells.iter()
+    }
+
+    /// Grid with rows and columns swapped.
+    pub fn transpose(&self) -> Grid<T>
+    where
+        T: Clone,
+    {
+        Grid::new(
+            self.height,
+            self.width,
+            self.columns().flatten().cloned().collect(),
+        )
+    }
+
+    /// Grid with the function applied to every cell.
+    pub fn map<U>(&self, f: impl FnMut(&T) -> U) -> Grid<U> {
+        Grid::new(self.width, self.height, self.cells.iter().map(f).collect())
+    }
+
+    /// Owning version of `map`.
+    pub(crate) fn into_map<U>(self, f: impl FnMut(T) -> U) -> Grid<U> {
+        Grid::new(
+            self.width,
+            self.height,
+            self.cells.into_iter().map(f).collect(),
+        )
+    }
+
+    /// Position of the first cell equal to the given value in row-major order.
+    /// Useful for markers such as `S` and `E`.
+    pub fn find(&self, value: &T) -> Option<Position>
+    where
+        T: PartialEq,
+    {
+        self.cells
+            .iter()
+            .position(|cell| cell == value)
+            .map(|index| (index / self.width, index % self.width))
+    }
+}
+
+impl<T> Index<Position> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, pos: Position) -> &T {
+        let (width, height) = (self.width, self.height);
+        self.get(pos).unwrap_or_else(|| {
+            panic!(
+                "Position {:?} is outside the grid of {}x{}.",
+                pos, width, height
+            )
+        })
+    }
+}
+
+impl<T> IndexMut<Position> for Grid<T> {
+    fn index_mut(&mut self, pos: Position) -> &mut T {
+        let (width, height) = (self.width, self.height);
+        self.get_mut(pos).unwrap_or_else(|| {
+            panic!(
+                "Position {:?} is outside the grid of {}x{}.",
+                pos, width, height
+            )
+        })
+    }
+}
+
+impl<T: fmt::Display> fmt::Display for Grid<T> {
+    /// Cells of each row written next to each other, rows separated by `\n`.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for (index, row) in self.rows().enumerate() {
+            if index > 0 {
+                writeln!(f)?;
+            }
+            for cell in row {
+                write!(f, "{}", cell)?;
+            }
+        }
+        Ok(())
+    }
+}
+
+#[test]
+fn test_neighbours() {
+    let grid = Grid::filled(3, 3, 0);
+
+    let corner: Vec<Position> = grid.neighbours4((0, 0)).collect();
+    assert_eq!(corner, vec![(1, 0), (0, 1)]);
+    let corner: Vec<Position> = grid.neighbours8((2, 2)).collect();
+    assert_eq!(corner, vec![(1, 1), (1, 2), (2, 1)]);
+
+    let edge: Vec<Position> = grid.neighbours4((0, 1)).collect();
+    assert_eq!(edge, vec![(1, 1), (0, 0), (0, 2)]);
+    assert_eq!(grid.neighbours8((1, 0)).count(), 5);
+
+    assert_eq!(grid.neighbours4((1, 1)).count(), 4);
+    assert_eq!(grid.neighbours8((1, 1)).count(), 8);
+}
+
+#[test]
+fn test_transpose() {
+    let grid = Grid::new(3, 2, vec![1, 2, 3, 4, 5, 6]);
+
+    assert_eq!(grid.column(1).copied().collect::<Vec<_>>(), vec![2, 5]);
+    assert_eq!(grid.transpose(), Grid::new(2, 3, vec![1, 4, 2, 5, 3, 6]));
+    assert_eq!(grid.transpose().row(2), &[3, 6]);
+    assert_eq!(grid.transpose().transpose(), grid);
+}
+
+#[test]
+#[should_panic(expected = "Column 3 is outside the grid.")]
+fn test_column_outside() {
+    let _ = Grid::new(3, 1, vec![1, 2, 3]).column(3);
+}
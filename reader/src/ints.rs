@@ -0,0 +1,42 @@
+/// Integers embedded in the text, in order, as slices of it.
+///
+/// An integer is a run of ASCII digits with an optional leading `-`. The `-` is
+/// only taken as a sign when it is not preceded by a letter or digit, so ranges
+/// such as `2-4` yield `2` and `4`.
+pub(crate) fn find_ints(text: &str) -> impl Iterator<Item = &str> + '_ {
+    let bytes = text.as_bytes();
+    let mut index = 0;
+
+    std::iter::from_fn(move || {
+        while index < bytes.len() {
+            let start = index;
+            let signed = bytes[index] == b'-'
+                && bytes.get(index + 1).is_some_and(u8::is_ascii_digit)
+                && (index == 0 || !bytes[index - 1].is_ascii_alphanumeric());
+            if signed {
+                index += 1;
+            }
+
+            if bytes[index].is_ascii_digit() {
+                while index < bytes.len() && bytes[index].is_ascii_digit() {
+                    index += 1;
+                }
+                return Some(&text[start..index]);
+            }
+            index += 1;
+        }
+        None
+    })
+}
+
+#[test]
+fn test_find_ints() {
+    let ints = |text| find_ints(text).collect::<Vec<&str>>();
+
+    assert_eq!(ints("2-4"), vec!["2", "4"]);
+    assert_eq!(ints("x=-3"), vec!["-3"]);
+    assert_eq!(ints("-"), Vec::<&str>::new());
+    assert_eq!(ints("- -"), Vec::<&str>::new());
+    assert_eq!(ints("-12 a-3 --4"), vec!["-12", "3", "-4"]);
+    assert_eq!(ints("move 10 from 2 to 7"), vec!["10", "2", "7"]);
+}
@@ -0,0 +1,212 @@
+use crate::{error::Lenient, BoxError, Location, ReaderError};
+use std::{fmt::Debug, marker::PhantomData, ops::Range, str::FromStr};
+
+/// How the content of a `Reader` is divided into parts.
+#[derive(Debug, Clone)]
+pub(crate) enum Split {
+    /// Lines as produced by `str::lines`.
+    Lines,
+    /// Blocks separated by one or more empty lines.
+    Blocks,
+    /// Parts separated by a pattern as produced by `str::split`.
+    Pattern(String),
+}
+
+/// Owning cursor over the parts of the content. Finds the next part on demand
+/// instead of splitting the whole content up front.
+#[derive(Debug)]
+pub(crate) struct Parts {
+    text: String,
+    path: Option<String>,
+    /// Lines preceding the content in the original input.
+    line_offset: usize,
+    split: Split,
+    pos: usize,
+    count: usize,
+    finished: bool,
+}
+
+impl Parts {
+    pub(crate) fn new(
+        text: String,
+        path: Option<String>,
+        line_offset: usize,
+        split: Split,
+    ) -> Self {
+        if let Split::Pattern(pattern) = &split {
+            assert!(!pattern.is_empty(), "Split pattern must not be empty.");
+        }
+
+        Parts {
+            text,
+            path,
+            line_offset,
+            split,
+            pos: 0,
+            count: 0,
+            finished: false,
+        }
+    }
+
+    /// Byte range of the next part and its 1-based index.
+    fn next_range(&mut self) -> Option<(usize, Range<usize>)> {
+        if self.finished {
+            return None;
+        }
+
+        // Consecutive empty lines are treated as a single separator of blocks.
+        if let Split::Blocks = self.split {
+            let rest = &self.text[self.pos..];
+            self.pos += rest.len() - rest.trim_start_matches('\n').len();
+        }
+
+        let rest = &self.text[self.pos..];
+        let separator = match &self.split {
+            Split::Lines | Split::Blocks if rest.is_empty() => {
+                self.finished = true;
+                return None;
+            }
+            Split::Lines => "\n",
+            Split::Blocks => "\n\n",
+            Split::Pattern(pattern) => pattern.as_str(),
+        };
+
+        let start = self.pos;
+        let end = match rest.find(separator) {
+            Some(index) => {
+                self.pos = start + index + separator.len();
+                start + index
+            }
+            None => {
+                self.pos = self.text.len();
+                self.finished = true;
+                self.text.len()
+            }
+        };
+
+        self.count += 1;
+        Some((self.count, start..end))
+    }
+
+    /// Lines drop a trailing `\r` like `str::lines` in case the content was
+    /// created without normalizing line changes.
+    fn part(&self, range: Range<usize>) -> &str {
+        let part = &self.text[range];
+        match self.split {
+            Split::Lines => part.strip_suffix('\r').unwrap_or(part),
+            _ => part,
+        }
+    }
+
+    fn parse<T>(&self, index: usize, range: Range<usize>) -> Result<T, ReaderError>
+    where
+        T: FromStr,
+        T::Err: Into<BoxError>,
+    {
+        let part = self.part(range);
+        part.parse::<T>().map_err(|err| {
+            let block = matches!(self.split, Split::Blocks).then_some(index);
+            let location = Location::of(&self.text, part, self.path.as_deref(), block)
+                .shifted(self.line_offset);
+            ReaderError::parse::<T>(part, err, location)
+        })
+    }
+}
+
+/// Owning iterator that lazily parses parts of the content into `T`.
+///
+/// Yields `Err` for the first part that fails to parse and stops afterwards,
+/// so `collect::<Result<Vec<T>, _>>()` never parses past an error.
+#[derive(Debug)]
+pub struct TryParse<T> {
+    parts: Parts,
+    marker: PhantomData<fn() -> T>,
+}
+
+impl<T> TryParse<T> {
+    pub(crate) fn new(parts: Parts) -> Self {
+        TryParse {
+            parts,
+            marker: PhantomData,
+        }
+    }
+}
+
+impl<T> Iterator for TryParse<T>
+where
+    T: FromStr,
+    T::Err: Into<BoxError>,
+{
+    type Item = Result<T, ReaderError>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let (index, range) = self.parts.next_range()?;
+        let parsed = self.parts.parse(index, range);
+        if parsed.is_err() {
+            self.parts.finished = true;
+        }
+        Some(parsed)
+    }
+}
+
+/// Owning iterator that lazily parses parts of the content into `T`,
+/// panicking on the first part that fails to parse.
+#[derive(Debug)]
+pub struct Parse<T> {
+    inner: TryParse<Lenient<T>>,
+}
+
+impl<T> Parse<T> {
+    pub(crate) fn new(parts: Parts) -> Self {
+        Parse {
+            inner: TryParse::new(parts),
+        }
+    }
+}
+
+impl<T> Iterator for Parse<T>
+where
+    T: FromStr,
+    T::Err: Debug,
+{
+    type Item = T;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.inner
+            .next()
+            .map(|parsed| parsed.unwrap_or_else(|err| panic!("{}", err)).0)
+    }
+}
+
+/// Owning iterator that lazily passes parts of the content through a function.
+#[derive(Debug)]
+pub struct ParseWith<T, F = fn(&str) -> T> {
+    parts: Parts,
+    f: F,
+    marker: PhantomData<fn() -> T>,
+}
+
+impl<T, F> ParseWith<T, F>
+where
+    F: FnMut(&str) -> T,
+{
+    pub(crate) fn new(parts: Parts, f: F) -> Self {
+        ParseWith {
+            parts,
+            f,
+            marker: PhantomData,
+        }
+    }
+}
+
+impl<T, F> Iterator for ParseWith<T, F>
+where
+    F: FnMut(&str) -> T,
+{
+    type Item = T;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let (_, range) = self.parts.next_range()?;
+        Some((self.f)(self.parts.part(range)))
+    }
+}
@@ -1,13 +1,227 @@
 #![allow(dead_code)]
 
+mod captures;
+mod columns;
+mod compression;
+mod error;
+pub mod format;
+mod grid;
+mod ints;
+mod iter;
+mod location;
+mod normalize;
+mod resolve;
+mod section;
+mod stream;
+
+pub use captures::{capture, Captures, FromCaptures, Group};
+pub use columns::{Column, ColumnError, FromColumns, Rest};
+pub use compression::InputFile;
+pub use error::{BoxError, ReaderError};
+pub use format::FormatError;
+pub use grid::{Grid, Position};
+pub use iter::{Parse, ParseWith, TryParse};
+pub use location::Location;
+pub use normalize::Normalize;
+/// Derive `FromStr` from a format string, see the `reader-derive` crate.
+/// ## Example
+/// ```
+/// use reader::PuzzleParse;
+///
+/// #[derive(PuzzleParse)]
+/// #[parse("Valve {name} has flow rate={flow}; tunnels lead to valves {tunnels:, }")]
+/// #[parse("Valve {name} has flow rate={flow}; tunnel leads to valve {tunnels:, }")]
+/// struct Valve {
+///     name: String,
+///     flow: usize,
+///     tunnels: Vec<String>,
+/// }
+///
+/// let input: reader::Reader = "Valve AA has flow rate=0; tunnels lead to valves DD, II\n\
+///                              Valve HH has flow rate=22; tunnel leads to valve GG"
+///     .parse()
+///     .unwrap();
+/// let valves = input.lines_as::<Valve>();
+/// assert_eq!(valves[0].tunnels, vec!["DD", "II"]);
+/// assert_eq!(valves[1].flow, 22);
+/// ```
+/// ## Errors
+/// Mistakes in the format are reported when compiling, such as an unknown field
+/// ```compile_fail
+/// #[derive(reader::PuzzleParse)]
+/// #[parse("{begin}-{stop}")]
+/// struct Range {
+///     begin: usize,
+///     end: usize,
+/// }
+/// ```
+/// a field that appears twice
+/// ```compile_fail
+/// #[derive(reader::PuzzleParse)]
+/// #[parse("{begin}-{begin}")]
+/// struct Range {
+///     begin: usize,
+/// }
+/// ```
+/// placeholders without literal text between them
+/// ```compile_fail
+/// #[derive(reader::PuzzleParse)]
+/// #[parse("{begin}{end}")]
+/// struct Range {
+///     begin: usize,
+///     end: usize,
+/// }
+/// ```
+/// or a missing format.
+/// ```compile_fail
+/// #[derive(reader::PuzzleParse)]
+/// enum Instruction {
+///     #[parse("noop")]
+///     Noop,
+///     Addx(isize),
+/// }
+/// ```
+pub use reader_derive::PuzzleParse;
+pub use resolve::{candidates, resolve, INPUT_DIR_VAR};
+pub use section::FromSection;
+pub use stream::{stream, stream_with, try_stream, try_stream_with, StreamParse, StreamReader};
+
+use error::{DebugError, Lenient};
+use ints::find_ints;
+use iter::{Parts, Split};
+use section::parse_section;
+use std::{fmt::Debug, io::Read};
+
 /// Reader used to parse text file content.
 ///
-/// Use `reader::open(path: &str)` to create a new `Reader` from a text file.
+/// Use `reader::open(path: &str)` to create a new `Reader` from a text file,
+/// `reader::stdin()` to read standard input, `reader::embedded!()` to bake a
+/// file into the binary or `"..".parse::<Reader>()` for in-memory content.
+///
+/// The content is normalized once when the `Reader` is created, so every method
+/// sees the same `\n` line changes and no trailing empty lines, see `Normalize`.
+/// ## Example
+/// ```
+/// let input: reader::Reader = "1\n2\n3".parse().unwrap();
+/// assert_eq!(input.lines_as::<usize>(), vec![1, 2, 3]);
+/// ```
+#[derive(Debug, Clone)]
 pub struct Reader {
     text: String,
+    path: Option<String>,
+    /// Lines preceding the content in the original input, non-zero for sections.
+    line_offset: usize,
 }
 
 impl Reader {
+    /// Create a `Reader` from the given content normalized with `Normalize::default()`.
+    fn new(text: String, path: Option<String>) -> Self {
+        Reader {
+            text: Normalize::default().repair(text),
+            path,
+            line_offset: 0,
+        }
+    }
+
+    /// Create a `Reader` from the given content, returning `ReaderError::Empty`
+    /// if it contains only whitespace and `ReaderError::Malformed` if it fails
+    /// a strict normalization.
+    fn non_empty(text: String, path: &str, normalize: Normalize) -> Result<Self, ReaderError> {
+        if text.trim().is_empty() {
+            return Err(ReaderError::Empty {
+                path: path.to_string(),
+            });
+        }
+
+        Ok(Reader {
+            text: normalize.apply(text, Some(path))?,
+            path: Some(path.to_string()),
+            line_offset: 0,
+        })
+    }
+
+    /// Create a `Reader` from in-memory content with the given normalization.
+    ///
+    /// Returns `ReaderError::Empty` if the content contains only whitespace and
+    /// `ReaderError::Malformed` if strict normalization rejects it.
+    pub fn try_from_text(text: &str, normalize: Normalize) -> Result<Reader, ReaderError> {
+        Reader::non_empty(text.to_string(), STRING, normalize)
+    }
+
+    /// Used by `reader::embedded!`.
+    #[doc(hidden)]
+    pub fn from_embedded(text: &str, path: &str) -> Self {
+        Reader::new(text.to_string(), Some(path.to_string()))
+    }
+
+    /// Path or name of the source the content was read from, if any.
+    pub fn path(&self) -> Option<&str> {
+        self.path.as_deref()
+    }
+
+    /// Current content lines without consuming the `Reader`.
+    pub fn lines_ref(&self) -> impl Iterator<Item = &str> + '_ {
+        self.text.lines()
+    }
+
+    /// Current content blocks divided by empty lines without consuming the `Reader`.
+    /// Consecutive empty lines are treated as a single separator, like in
+    /// `StreamReader`. See `split_on_empty_line`.
+    pub fn blocks_ref(&self) -> impl Iterator<Item = &str> + '_ {
+        self.text
+            .split("\n\n")
+            .map(|block| block.trim_start_matches('\n'))
+            .filter(|block| !block.is_empty())
+    }
+
+    /// Current content split on the given pattern without consuming the `Reader`.
+    /// See `split_line_into`.
+    pub fn split_ref<'a>(&'a self, pattern: &'a str) -> impl Iterator<Item = &'a str> + 'a {
+        self.text.split(pattern)
+    }
+
+    /// Current content lines lazily parsed into the given type without consuming
+    /// the `Reader`. See `lines_as`.
+    /// ## Example
+    /// ```rs
+    /// let input = open("file.txt");
+    /// let sum: usize = input.lines_ref_as::<usize>().map(Result::unwrap).sum();
+    /// let max = input.lines_ref_as::<usize>().map(Result::unwrap).max();
+    /// ```
+    pub fn lines_ref_as<T>(&self) -> impl Iterator<Item = Result<T, ReaderError>> + '_
+    where
+        T: core::str::FromStr,
+        T::Err: Into<BoxError>,
+    {
+        self.lines_ref().map(|line| self.parse_part(line, None))
+    }
+
+    /// Current content blocks lazily parsed into the given type without consuming
+    /// the `Reader`. See `split_on_empty_line_into`.
+    pub fn blocks_ref_as<T>(&self) -> impl Iterator<Item = Result<T, ReaderError>> + '_
+    where
+        T: core::str::FromStr,
+        T::Err: Into<BoxError>,
+    {
+        self.blocks_ref()
+            .enumerate()
+            .map(|(index, block)| self.parse_part(block, Some(index + 1)))
+    }
+
+    /// Current content split on the given pattern and lazily parsed into the given
+    /// type without consuming the `Reader`. See `split_line_into`.
+    pub fn split_ref_as<'a, T>(
+        &'a self,
+        pattern: &'a str,
+    ) -> impl Iterator<Item = Result<T, ReaderError>> + 'a
+    where
+        T: core::str::FromStr,
+        T::Err: Into<BoxError>,
+    {
+        self.split_ref(pattern)
+            .map(|part| self.parse_part(part, None))
+    }
+
     /// Current content as a single `String`.
     pub fn text(self) -> String {
         self.text
@@ -32,11 +246,7 @@ impl Reader {
     /// // ["a\nb", "c\nd"]
     /// ```
     pub fn split_on_empty_line(self) -> Vec<String> {
-        self.text
-            .replace("\r\n", "\n")
-            .split("\n\n")
-            .map(|part| part.to_string())
-            .collect()
+        self.blocks_ref().map(|part| part.to_string()).collect()
     }
 
     /// Current content divided by empty lines and the resulting blocks
@@ -57,7 +267,8 @@ impl Reader {
     /// ```
     /// ## Note
     /// You can provide support for custom structs by implementing the `FromStr` trait.
-    /// ```
+    /// ```no_run
+    /// # use reader::open;
     /// use std::{str::FromStr, string::ParseError};
     ///
     /// struct Example {
@@ -82,25 +293,26 @@ impl Reader {
     /// //
     /// // a = 3
     /// // b = 4
-    /// open("input.txt").split_on_empty_line_into::<Example>()
+    /// open("input.txt").split_on_empty_line_into::<Example>();
     /// // [Example { a: 1, b: 2 }, Example { a: 3, b: 4 }]
     /// ```
     pub fn split_on_empty_line_into<T>(self) -> Vec<T>
     where
         T: core::str::FromStr,
+        T::Err: Debug,
     {
-        self.text
-            .replace("\r\n", "\n")
-            .split("\n\n")
-            .map(|part| match part.parse::<T>() {
-                Ok(value) => value,
-                _ => panic!(
-                    "Unable to parse \"{:?}\" into the given type \"{:?}\".",
-                    part,
-                    std::any::type_name::<T>()
-                ),
-            })
-            .collect()
+        lenient(self.try_split_on_empty_line_into())
+    }
+
+    /// Fallible version of `split_on_empty_line_into`.
+    ///
+    /// Returns `ReaderError::Parse` for the first block that could not be parsed.
+    pub fn try_split_on_empty_line_into<T>(self) -> Result<Vec<T>, ReaderError>
+    where
+        T: core::str::FromStr,
+        T::Err: Into<BoxError>,
+    {
+        self.blocks_ref_as().collect()
     }
 
     /// Process the file content as a single string, splitting it on a given pattern, and
@@ -114,7 +326,8 @@ impl Reader {
     /// ```
     /// ## Note
     /// You can provide support for custom structs by implementing the `FromStr` trait.
-    /// ```
+    /// ```no_run
+    /// # use reader::open;
     /// use std::{str::FromStr, string::ParseError};
     ///
     /// struct Example {
@@ -134,24 +347,26 @@ impl Reader {
     /// }
     /// // input.txt
     /// // 1 2,3 4
-    /// open("input.txt").split_line_into::<Example>(",")
+    /// open("input.txt").split_line_into::<Example>(",");
     /// // [Example { a: 1, b: 2 }, Example { a: 3, b: 4 }]
     /// ```
     pub fn split_line_into<T>(self, pattern: &str) -> Vec<T>
     where
         T: core::str::FromStr,
+        T::Err: Debug,
     {
-        self.text
-            .split(pattern)
-            .map(|part| match part.parse::<T>() {
-                Ok(value) => value,
-                _ => panic!(
-                    "Unable to parse \"{:?}\" into the given type \"{:?}\".",
-                    part,
-                    std::any::type_name::<T>()
-                ),
-            })
-            .collect()
+        lenient(self.try_split_line_into(pattern))
+    }
+
+    /// Fallible version of `split_line_into`.
+    ///
+    /// Returns `ReaderError::Parse` for the first part that could not be parsed.
+    pub fn try_split_line_into<T>(self, pattern: &str) -> Result<Vec<T>, ReaderError>
+    where
+        T: core::str::FromStr,
+        T::Err: Into<BoxError>,
+    {
+        self.split_ref_as(pattern).collect()
     }
 
     /// Return the file content lines parsed into the given type.
@@ -167,7 +382,8 @@ impl Reader {
     /// ```
     /// ## Note
     /// You can provide support for custom structs by implementing the `FromStr` trait.
-    /// ```
+    /// ```no_run
+    /// # use reader::open;
     /// use std::{str::FromStr, string::ParseError};
     ///
     /// struct Example {
@@ -188,29 +404,34 @@ impl Reader {
     /// // input.txt
     /// // 1 2
     /// // 3 4
-    /// open("input.txt").lines_as::<Example>()
+    /// open("input.txt").lines_as::<Example>();
     /// // [Example { a: 1, b: 2 }, Example { a: 3, b: 4 }]
     /// ```
     pub fn lines_as<T>(self) -> Vec<T>
     where
         T: core::str::FromStr,
+        T::Err: Debug,
     {
-        self.text
-            .lines()
-            .map(|line| match line.parse::<T>() {
-                Ok(value) => value,
-                _ => panic!(
-                    "Unable to parse \"{:?}\" into the given type \"{:?}\".",
-                    line,
-                    std::any::type_name::<T>()
-                ),
-            })
-            .collect()
+        lenient(self.try_lines_as())
+    }
+
+    /// Fallible version of `lines_as`.
+    ///
+    /// Returns `ReaderError::Parse` for the first line that could not be parsed.
+    pub fn try_lines_as<T>(self) -> Result<Vec<T>, ReaderError>
+    where
+        T: core::str::FromStr,
+        T::Err: Into<BoxError>,
+    {
+        self.lines_ref_as().collect()
     }
 
     /// Return the file content lines passed through the given function.
+    ///
+    /// The function can be a closure that captures state, such as a lookup table.
     /// ## Example
-    /// ```
+    /// ```no_run
+    /// # use reader::open;
     /// fn my_parse_function(str: &str) -> usize {
     ///     str.parse().unwrap_or(0)
     /// }
@@ -219,12 +440,13 @@ impl Reader {
     /// // 1
     /// // a
     /// // 3
-    /// open("input.txt").parse_lines(my_parse_function)
+    /// open("input.txt").parse_lines(my_parse_function);
     /// // [1, 0, 3]
     /// ```
     /// ## Note
     /// This provides an alternative way to create custom structs using a non-trait function.
-    /// ```
+    /// ```no_run
+    /// # use reader::open;
     /// struct Example {
     ///     a: usize,
     ///     b: usize,
@@ -242,17 +464,1046 @@ impl Reader {
     /// // input.txt
     /// // 1 2
     /// // 3 4
-    /// open("input.txt").parse_lines(Example::from_str)
+    /// open("input.txt").parse_lines(Example::from_str);
     /// // [Example { a: 1, b: 2 }, Example { a: 3, b: 4 }]
     /// ```
-    pub fn parse_lines<T>(self, f: fn(&str) -> T) -> Vec<T> {
+    pub fn parse_lines<T>(self, f: impl FnMut(&str) -> T) -> Vec<T> {
         self.text.lines().map(f).collect()
     }
+
+    /// Return the file content blocks divided by empty lines with each line of
+    /// a block parsed into the given type.
+    /// ## Example
+    /// ```
+    /// let input: reader::Reader = "1000\n2000\n\n4000".parse().unwrap();
+    /// assert_eq!(input.blocks_of::<usize>(), vec![vec![1000, 2000], vec![4000]]);
+    /// ```
+    pub fn blocks_of<T>(self) -> Vec<Vec<T>>
+    where
+        T: core::str::FromStr,
+        T::Err: Debug,
+    {
+        self.try_blocks_of()
+            .unwrap_or_else(|err| panic!("{}", err))
+            .into_iter()
+            .map(|block: Vec<Lenient<T>>| block.into_iter().map(|value| value.0).collect())
+            .collect()
+    }
+
+    /// Fallible version of `blocks_of`.
+    ///
+    /// Returns `ReaderError::Parse` for the first line that could not be parsed,
+    /// located with both its line and block number.
+    pub fn try_blocks_of<T>(self) -> Result<Vec<Vec<T>>, ReaderError>
+    where
+        T: core::str::FromStr,
+        T::Err: Into<BoxError>,
+    {
+        self.blocks_ref()
+            .enumerate()
+            .map(|(index, block)| {
+                block
+                    .lines()
+                    .map(|line| self.parse_part(line, Some(index + 1)))
+                    .collect()
+            })
+            .collect()
+    }
+
+    /// Return the file content blocks divided by empty lines, each made of
+    /// exactly two lines parsed into the given type.
+    ///
+    /// Panics if a block has a different number of lines, see `try_pairs_of`.
+    /// ## Example
+    /// ```
+    /// let input: reader::Reader = "[1]\n[2]\n\n[3]\n[]".parse().unwrap();
+    /// let pairs = input.pairs_of::<String>();
+    /// assert_eq!(pairs[1], ("[3]".to_string(), "[]".to_string()));
+    /// ```
+    pub fn pairs_of<T>(self) -> Vec<(T, T)>
+    where
+        T: core::str::FromStr,
+        T::Err: Debug,
+    {
+        self.try_pairs_of()
+            .unwrap_or_else(|err| panic!("{}", err))
+            .into_iter()
+            .map(|(a, b): (Lenient<T>, Lenient<T>)| (a.0, b.0))
+            .collect()
+    }
+
+    /// Fallible version of `pairs_of`.
+    ///
+    /// Returns `ReaderError::BlockSize` for the first block that does not have
+    /// two lines and `ReaderError::Parse` for the first line that could not be parsed.
+    pub fn try_pairs_of<T>(self) -> Result<Vec<(T, T)>, ReaderError>
+    where
+        T: core::str::FromStr,
+        T::Err: Into<BoxError>,
+    {
+        self.blocks_ref()
+            .enumerate()
+            .map(|(index, block)| {
+                let lines = block.lines().collect::<Vec<&str>>();
+                if lines.len() != 2 {
+                    return Err(ReaderError::BlockSize {
+                        expected: 2,
+                        found: lines.len(),
+                        location: Box::new(self.locate(block, Some(index + 1))),
+                    });
+                }
+
+                Ok((
+                    self.parse_part(lines[0], Some(index + 1))?,
+                    self.parse_part(lines[1], Some(index + 1))?,
+                ))
+            })
+            .collect()
+    }
+
+    /// Return the file content blocks divided by empty lines passed through the
+    /// given function. See `parse_lines` and `split_on_empty_line`.
+    pub fn parse_blocks<T>(self, f: impl FnMut(&str) -> T) -> Vec<T> {
+        self.blocks_ref().map(f).collect()
+    }
+
+    /// Return the file content lines passed through the given fallible function
+    /// together with their 0-based index.
+    ///
+    /// Panics on the first error, see `try_parse_lines_indexed`.
+    /// ## Example
+    /// ```
+    /// let input: reader::Reader = "a\nb\na".parse().unwrap();
+    /// let mut names = std::collections::HashMap::new();
+    /// let ids = input.parse_lines_indexed(|index, line| {
+    ///     Ok::<_, std::convert::Infallible>(*names.entry(line.to_string()).or_insert(index))
+    /// });
+    /// assert_eq!(ids, vec![0, 1, 0]);
+    /// ```
+    pub fn parse_lines_indexed<T, E>(self, mut f: impl FnMut(usize, &str) -> Result<T, E>) -> Vec<T>
+    where
+        E: Debug,
+    {
+        self.try_parse_lines_indexed(|index, part| f(index, part).map_err(DebugError::new))
+            .unwrap_or_else(|err| panic!("{}", err))
+    }
+
+    /// Fallible version of `parse_lines_indexed`.
+    ///
+    /// Returns `ReaderError::Parse` with the location of the first line the
+    /// function returned an error for.
+    pub fn try_parse_lines_indexed<T, E>(
+        self,
+        mut f: impl FnMut(usize, &str) -> Result<T, E>,
+    ) -> Result<Vec<T>, ReaderError>
+    where
+        E: Into<BoxError>,
+    {
+        self.lines_ref()
+            .enumerate()
+            .map(|(index, line)| {
+                f(index, line)
+                    .map_err(|err| ReaderError::parse::<T>(line, err, self.locate(line, None)))
+            })
+            .collect()
+    }
+
+    /// Block version of `parse_lines_indexed`, the index counts blocks.
+    pub fn parse_blocks_indexed<T, E>(
+        self,
+        mut f: impl FnMut(usize, &str) -> Result<T, E>,
+    ) -> Vec<T>
+    where
+        E: Debug,
+    {
+        self.try_parse_blocks_indexed(|index, part| f(index, part).map_err(DebugError::new))
+            .unwrap_or_else(|err| panic!("{}", err))
+    }
+
+    /// Fallible version of `parse_blocks_indexed`.
+    ///
+    /// Returns `ReaderError::Parse` with the location of the first block the
+    /// function returned an error for.
+    pub fn try_parse_blocks_indexed<T, E>(
+        self,
+        mut f: impl FnMut(usize, &str) -> Result<T, E>,
+    ) -> Result<Vec<T>, ReaderError>
+    where
+        E: Into<BoxError>,
+    {
+        self.blocks_ref()
+            .enumerate()
+            .map(|(index, block)| {
+                f(index, block).map_err(|err| {
+                    ReaderError::parse::<T>(block, err, self.locate(block, Some(index + 1)))
+                })
+            })
+            .collect()
+    }
+
+    /// Lazy version of `lines_as`. Lines are parsed one at a time as the
+    /// iterator is advanced.
+    /// ## Example
+    /// ```rs
+    /// // file.txt
+    /// // 1
+    /// // 2
+    /// // 3
+    /// open("file.txt").lines_iter_as::<usize>().filter(|n| n % 2 == 1).sum::<usize>()
+    /// // 4
+    /// ```
+    pub fn lines_iter_as<T>(self) -> Parse<T>
+    where
+        T: core::str::FromStr,
+        T::Err: Debug,
+    {
+        Parse::new(self.into_parts(Split::Lines))
+    }
+
+    /// Fallible version of `lines_iter_as`. The iterator stops after the
+    /// first line that could not be parsed.
+    /// ## Example
+    /// ```rs
+    /// let numbers: Result<Vec<usize>, ReaderError> =
+    ///     open("file.txt").try_lines_iter_as::<usize>().collect();
+    /// ```
+    pub fn try_lines_iter_as<T>(self) -> TryParse<T>
+    where
+        T: core::str::FromStr,
+        T::Err: Into<BoxError>,
+    {
+        TryParse::new(self.into_parts(Split::Lines))
+    }
+
+    /// Lazy version of `split_on_empty_line_into`.
+    pub fn split_on_empty_line_iter_into<T>(self) -> Parse<T>
+    where
+        T: core::str::FromStr,
+        T::Err: Debug,
+    {
+        Parse::new(self.into_parts(Split::Blocks))
+    }
+
+    /// Fallible version of `split_on_empty_line_iter_into`. The iterator stops
+    /// after the first block that could not be parsed.
+    pub fn try_split_on_empty_line_iter_into<T>(self) -> TryParse<T>
+    where
+        T: core::str::FromStr,
+        T::Err: Into<BoxError>,
+    {
+        TryParse::new(self.into_parts(Split::Blocks))
+    }
+
+    /// Lazy version of `split_line_into`.
+    ///
+    /// Panics if the pattern is empty.
+    pub fn split_line_iter_into<T>(self, pattern: &str) -> Parse<T>
+    where
+        T: core::str::FromStr,
+        T::Err: Debug,
+    {
+        Parse::new(self.into_parts(Split::Pattern(pattern.to_string())))
+    }
+
+    /// Fallible version of `split_line_iter_into`. The iterator stops after the
+    /// first part that could not be parsed.
+    ///
+    /// Panics if the pattern is empty.
+    pub fn try_split_line_iter_into<T>(self, pattern: &str) -> TryParse<T>
+    where
+        T: core::str::FromStr,
+        T::Err: Into<BoxError>,
+    {
+        TryParse::new(self.into_parts(Split::Pattern(pattern.to_string())))
+    }
+
+    /// Lazy version of `parse_lines`.
+    pub fn parse_lines_iter<T, F>(self, f: F) -> ParseWith<T, F>
+    where
+        F: FnMut(&str) -> T,
+    {
+        ParseWith::new(self.into_parts(Split::Lines), f)
+    }
+
+    /// Lazy version of `parse_blocks`.
+    pub fn parse_blocks_iter<T, F>(self, f: F) -> ParseWith<T, F>
+    where
+        F: FnMut(&str) -> T,
+    {
+        ParseWith::new(self.into_parts(Split::Blocks), f)
+    }
+
+    /// Return the file content lines matched against a regular expression and
+    /// converted from the capture groups into the given type.
+    ///
+    /// The expression is compiled once and must match each line in full.
+    /// Tuples of `FromStr` types are parsed from the numbered groups, see `FromCaptures`.
+    /// ## Example
+    /// ```
+    /// let input: reader::Reader = "move 1 from 2 to 1\nmove 3 from 1 to 3".parse().unwrap();
+    /// let moves = input.lines_matching::<(usize, usize, usize)>(r"move (\d+) from (\d+) to (\d+)");
+    /// assert_eq!(moves, vec![(1, 2, 1), (3, 1, 3)]);
+    /// ```
+    pub fn lines_matching<T>(self, pattern: &str) -> Vec<T>
+    where
+        T: FromCaptures,
+    {
+        self.try_lines_matching(pattern)
+            .unwrap_or_else(|err| panic!("{}", err))
+    }
+
+    /// Fallible version of `lines_matching`.
+    ///
+    /// Returns `ReaderError::Pattern` if the expression is invalid,
+    /// `ReaderError::NoMatch` for the first line that does not match and
+    /// `ReaderError::Parse` for the first line whose captures can not be converted.
+    pub fn try_lines_matching<T>(self, pattern: &str) -> Result<Vec<T>, ReaderError>
+    where
+        T: FromCaptures,
+    {
+        let re = regex::Regex::new(&format!("^(?:{})$", pattern)).map_err(|source| {
+            ReaderError::Pattern {
+                pattern: pattern.to_string(),
+                source,
+            }
+        })?;
+
+        self.lines_ref()
+            .map(|line| {
+                let location = || self.locate(line, None);
+                let caps = re.captures(line).ok_or_else(|| ReaderError::NoMatch {
+                    pattern: pattern.to_string(),
+                    location: Box::new(location()),
+                })?;
+
+                T::from_captures(&caps)
+                    .map_err(|err| ReaderError::parse::<T>(line, err, location()))
+            })
+            .collect()
+    }
+
+    /// Return each line of the file content split on the given delimiter into a
+    /// tuple of typed columns.
+    ///
+    /// A `Rest` in the last position collects every remaining field.
+    /// ## Example
+    /// ```
+    /// let input: reader::Reader = "R 4\nU 12".parse().unwrap();
+    /// let moves = input.columns::<(char, usize)>(" ");
+    /// assert_eq!(moves, vec![('R', 4), ('U', 12)]);
+    /// ```
+    pub fn columns<T>(self, delimiter: &str) -> Vec<T>
+    where
+        T: FromColumns,
+    {
+        self.try_columns(delimiter)
+            .unwrap_or_else(|err| panic!("{}", err))
+    }
+
+    /// Fallible version of `columns`.
+    ///
+    /// Returns `ReaderError::EmptyDelimiter` if the delimiter is empty,
+    /// `ReaderError::Columns` for the first line with the wrong number of fields
+    /// and `ReaderError::Parse` for the first field that could not be parsed.
+    pub fn try_columns<T>(self, delimiter: &str) -> Result<Vec<T>, ReaderError>
+    where
+        T: FromColumns,
+    {
+        if delimiter.is_empty() {
+            return Err(ReaderError::EmptyDelimiter);
+        }
+
+        self.lines_ref()
+            .map(|line| self.parse_columns(line, line.split(delimiter).collect()))
+            .collect()
+    }
+
+    /// Version of `columns` where fields are separated by any amount of whitespace.
+    pub fn whitespace_columns<T>(self) -> Vec<T>
+    where
+        T: FromColumns,
+    {
+        self.try_whitespace_columns()
+            .unwrap_or_else(|err| panic!("{}", err))
+    }
+
+    /// Fallible version of `whitespace_columns`.
+    pub fn try_whitespace_columns<T>(self) -> Result<Vec<T>, ReaderError>
+    where
+        T: FromColumns,
+    {
+        self.lines_ref()
+            .map(|line| self.parse_columns(line, line.split_whitespace().collect()))
+            .collect()
+    }
+
+    fn parse_columns<T>(&self, line: &str, fields: Vec<&str>) -> Result<T, ReaderError>
+    where
+        T: FromColumns,
+    {
+        T::from_columns(&fields).map_err(|err| match err {
+            ColumnError::Count { expected, rest } => ReaderError::Columns {
+                expected,
+                rest,
+                found: fields.len(),
+                location: Box::new(self.locate(line, None)),
+            },
+            ColumnError::Parse {
+                field,
+                type_name,
+                source,
+            } => ReaderError::Parse {
+                text: fields[field].to_string(),
+                type_name,
+                source,
+                location: Box::new(self.locate(fields[field], None)),
+            },
+        })
+    }
+
+    /// Return every integer in the file content parsed into the given type.
+    ///
+    /// Integers are runs of digits with an optional leading `-`, everything
+    /// between them is ignored. A `-` directly after a letter or digit is not a sign.
+    /// ## Example
+    /// ```
+    /// let input: reader::Reader = "x=-3, y=12\nrate=7;".parse().unwrap();
+    /// assert_eq!(input.ints::<i32>(), vec![-3, 12, 7]);
+    /// ```
+    pub fn ints<T>(self) -> Vec<T>
+    where
+        T: core::str::FromStr,
+        T::Err: Debug,
+    {
+        lenient(self.try_ints())
+    }
+
+    /// Fallible version of `ints`.
+    pub fn try_ints<T>(self) -> Result<Vec<T>, ReaderError>
+    where
+        T: core::str::FromStr,
+        T::Err: Into<BoxError>,
+    {
+        find_ints(&self.text)
+            .map(|int| self.parse_part(int, None))
+            .collect()
+    }
+
+    /// Return the integers of each line of the file content parsed into the given type.
+    /// ## Example
+    /// ```rs
+    /// // file.txt
+    /// // move 1 from 2 to 1
+    /// // Test: divisible by 23
+    /// open("file.txt").ints_per_line::<usize>()
+    /// // vec![vec![1, 2, 1], vec![23]]
+    /// ```
+    pub fn ints_per_line<T>(self) -> Vec<Vec<T>>
+    where
+        T: core::str::FromStr,
+        T::Err: Debug,
+    {
+        self.try_ints_per_line()
+            .unwrap_or_else(|err| panic!("{}", err))
+            .into_iter()
+            .map(|line: Vec<Lenient<T>>| line.into_iter().map(|value| value.0).collect())
+            .collect()
+    }
+
+    /// Fallible version of `ints_per_line`.
+    pub fn try_ints_per_line<T>(self) -> Result<Vec<Vec<T>>, ReaderError>
+    where
+        T: core::str::FromStr,
+        T::Err: Into<BoxError>,
+    {
+        self.lines_ref()
+            .map(|line| {
+                find_ints(line)
+                    .map(|int| self.parse_part(int, None))
+                    .collect()
+            })
+            .collect()
+    }
+
+    /// Return exactly `N` integers of each line of the file content parsed into the given type.
+    ///
+    /// Panics if a line contains a different number of integers, see `try_ints_per_line_array`.
+    /// ## Example
+    /// ```
+    /// let input: reader::Reader = "move 1 from 2 to 1\nmove 3 from 1 to 3".parse().unwrap();
+    /// let [amount, from, to] = input.ints_per_line_array::<usize, 3>()[1];
+    /// assert_eq!((amount, from, to), (3, 1, 3));
+    /// ```
+    pub fn ints_per_line_array<T, const N: usize>(self) -> Vec<[T; N]>
+    where
+        T: core::str::FromStr,
+        T::Err: Debug,
+    {
+        self.try_ints_per_line_array()
+            .unwrap_or_else(|err| panic!("{}", err))
+            .into_iter()
+            .map(|line: [Lenient<T>; N]| line.map(|value| value.0))
+            .collect()
+    }
+
+    /// Fallible version of `ints_per_line_array`.
+    ///
+    /// Returns `ReaderError::IntCount` for the first line that does not contain
+    /// exactly `N` integers.
+    pub fn try_ints_per_line_array<T, const N: usize>(self) -> Result<Vec<[T; N]>, ReaderError>
+    where
+        T: core::str::FromStr,
+        T::Err: Into<BoxError>,
+    {
+        self.lines_ref()
+            .map(|line| {
+                let ints = find_ints(line).collect::<Vec<&str>>();
+                if ints.len() != N {
+                    return Err(ReaderError::IntCount {
+                        expected: N,
+                        found: ints.len(),
+                        location: Box::new(self.locate(line, None)),
+                    });
+                }
+
+                let values = ints
+                    .into_iter()
+                    .map(|int| self.parse_part(int, None))
+                    .collect::<Result<Vec<T>, ReaderError>>()?;
+                Ok(values
+                    .try_into()
+                    .unwrap_or_else(|_| unreachable!("line has {} integers", N)))
+            })
+            .collect()
+    }
+
+    /// Current content as a grid where every character is parsed into the given type.
+    /// ## Example
+    /// ```rs
+    /// // file.txt
+    /// // 303
+    /// // 255
+    /// open("file.txt").grid::<usize>()
+    /// // Grid { width: 3, height: 2, .. }
+    /// ```
+    pub fn grid<T>(self) -> Grid<T>
+    where
+        T: core::str::FromStr,
+        T::Err: Debug,
+    {
+        self.try_grid()
+            .unwrap_or_else(|err| panic!("{}", err))
+            .into_map(|value: Lenient<T>| value.0)
+    }
+
+    /// Fallible version of `grid`.
+    ///
+    /// Returns `ReaderError::Parse` for the first character that could not be
+    /// parsed and `ReaderError::Ragged` for the first row whose length differs
+    /// from the first row.
+    pub fn try_grid<T>(self) -> Result<Grid<T>, ReaderError>
+    where
+        T: core::str::FromStr,
+        T::Err: Into<BoxError>,
+    {
+        let mut cells = vec![];
+        let mut width = None;
+        let mut height = 0;
+
+        for line in self.lines_ref() {
+            let mut count = 0;
+            for (index, char) in line.char_indices() {
+                cells.push(self.parse_part(&line[index..index + char.len_utf8()], None)?);
+                count += 1;
+            }
+
+            match width {
+                None => width = Some(count),
+                Some(expected) if expected != count => {
+                    return Err(ReaderError::Ragged {
+                        expected,
+                        found: count,
+                        location: Box::new(self.locate(line, None)),
+                    })
+                }
+                _ => (),
+            }
+            height += 1;
+        }
+
+        Ok(Grid::new(width.unwrap_or(0), height, cells))
+    }
+
+    /// Current content divided by empty lines into sections, each as its own `Reader`.
+    ///
+    /// Sections keep the path of the content, and errors from parsing them refer
+    /// to lines of the whole input.
+    pub fn sections(self) -> Vec<Reader> {
+        self.blocks_ref()
+            .map(|block| {
+                let offset = block.as_ptr() as usize - self.text.as_ptr() as usize;
+                Reader {
+                    text: block.to_string(),
+                    path: self.path.clone(),
+                    line_offset: self.line_offset + self.text[..offset].matches('\n').count(),
+                }
+            })
+            .collect()
+    }
+
+    /// Current content divided by empty lines into exactly two sections,
+    /// each parsed into its own type with `FromSection`.
+    ///
+    /// Panics if the section count differs or a section can not be parsed,
+    /// see `try_sections2`.
+    /// ## Example
+    /// ```rs
+    /// // file.txt
+    /// //     [D]
+    /// // [N] [C]
+    /// //  1   2
+    /// //
+    /// // move 1 from 2 to 1
+    /// let (drawing, moves): (Drawing, Vec<Instruction>) = open("file.txt").sections2();
+    /// ```
+    pub fn sections2<A, B>(self) -> (A, B)
+    where
+        A: FromSection,
+        B: FromSection,
+    {
+        self.try_sections2().unwrap_or_else(|err| panic!("{}", err))
+    }
+
+    /// Fallible version of `sections2`.
+    ///
+    /// Returns `ReaderError::SectionCount` if the content does not have exactly
+    /// two sections and `ReaderError::Section` for the first section that could
+    /// not be parsed.
+    pub fn try_sections2<A, B>(self) -> Result<(A, B), ReaderError>
+    where
+        A: FromSection,
+        B: FromSection,
+    {
+        let mut sections = self.exact_sections(2)?.into_iter();
+        Ok((
+            parse_section(1, sections.next().unwrap())?,
+            parse_section(2, sections.next().unwrap())?,
+        ))
+    }
+
+    /// Three section version of `sections2`.
+    pub fn sections3<A, B, C>(self) -> (A, B, C)
+    where
+        A: FromSection,
+        B: FromSection,
+        C: FromSection,
+    {
+        self.try_sections3().unwrap_or_else(|err| panic!("{}", err))
+    }
+
+    /// Fallible version of `sections3`.
+    pub fn try_sections3<A, B, C>(self) -> Result<(A, B, C), ReaderError>
+    where
+        A: FromSection,
+        B: FromSection,
+        C: FromSection,
+    {
+        let mut sections = self.exact_sections(3)?.into_iter();
+        Ok((
+            parse_section(1, sections.next().unwrap())?,
+            parse_section(2, sections.next().unwrap())?,
+            parse_section(3, sections.next().unwrap())?,
+        ))
+    }
+
+    fn exact_sections(self, expected: usize) -> Result<Vec<Reader>, ReaderError> {
+        let path = self.path.clone();
+        let sections = self.sections();
+
+        if sections.len() != expected {
+            return Err(ReaderError::SectionCount {
+                path,
+                expected,
+                found: sections.len(),
+            });
+        }
+
+        Ok(sections)
+    }
+
+    fn into_parts(self, split: Split) -> Parts {
+        Parts::new(self.text, self.path, self.line_offset, split)
+    }
+
+    /// Location of a part of the content. `part` must be a slice of the content.
+    fn locate(&self, part: &str, block: Option<usize>) -> Location {
+        Location::of(&self.text, part, self.path(), block).shifted(self.line_offset)
+    }
+
+    /// Parse a single part of the content into the given type, recording its
+    /// location in the input on failure. `part` must be a slice of the content.
+    fn parse_part<T>(&self, part: &str, block: Option<usize>) -> Result<T, ReaderError>
+    where
+        T: core::str::FromStr,
+        T::Err: Into<BoxError>,
+    {
+        part.parse::<T>().map_err(|err| {
+            let location = self.locate(part, block);
+            ReaderError::parse::<T>(part, err, location)
+        })
+    }
+}
+
+/// Unwrap the values parsed by a panicking method, see `Lenient`.
+fn lenient<T>(values: Result<Vec<Lenient<T>>, ReaderError>) -> Vec<T> {
+    values
+        .unwrap_or_else(|err| panic!("{}", err))
+        .into_iter()
+        .map(|value| value.0)
+        .collect()
 }
 
 /// Open text file in the given path and return its contents as a `Reader`.
+///
+/// Panics if the file can not be read, see `try_open`. An empty file gives an
+/// empty `Reader`.
 pub fn open(path: &str) -> Reader {
-    Reader {
-        text: std::fs::read_to_string(path).expect("file not found"),
+    open_with(path, Normalize::default())
+}
+
+/// Open text file in the given path and return its contents as a `Reader`.
+/// The content is normalized with `Normalize::default()`.
+///
+/// Relative paths are looked up from `AOC_INPUT_DIR` and the working directory,
+/// see `reader::candidates`. Use `reader::input!` to look them up from the
+/// calling crate's directory as well.
+/// Compressed files are decompressed with the `compression` feature, see `InputFile`.
+///
+/// Returns `ReaderError::NotFound` if the file does not exist in any of them,
+/// `ReaderError::Io` if it can not be read, `ReaderError::Compressed` if it is
+/// compressed without the `compression` feature and `ReaderError::Empty` if it
+/// contains only whitespace.
+pub fn try_open(path: &str) -> Result<Reader, ReaderError> {
+    try_open_with(path, Normalize::default())
+}
+
+/// Open text file in the given path with the given normalization.
+///
+/// Panics if the file can not be read or is rejected by strict normalization,
+/// see `try_open_with`. An empty file gives an empty `Reader`.
+/// ## Example
+/// ```rs
+/// let input = reader::open_with("input.txt", reader::Normalize::strict());
+/// ```
+pub fn open_with(path: &str, normalize: Normalize) -> Reader {
+    empty_or_panic(try_open_with(path, normalize))
+}
+
+/// Fallible version of `open_with`.
+pub fn try_open_with(path: &str, normalize: Normalize) -> Result<Reader, ReaderError> {
+    read_file(resolve(path, None)?, normalize)
+}
+
+/// Open text file in the given path, looking up relative paths from the given
+/// crate directory after `AOC_INPUT_DIR`, see `reader::candidates`. Used by
+/// `reader::input!` with the directory of the calling crate.
+///
+/// Panics if the file can not be read, see `try_open_in`. An empty file gives
+/// an empty `Reader`.
+pub fn open_in(crate_dir: &str, path: &str) -> Reader {
+    empty_or_panic(try_open_in(crate_dir, path))
+}
+
+/// Fallible version of `open_in`, see `try_open` for the errors.
+pub fn try_open_in(crate_dir: &str, path: &str) -> Result<Reader, ReaderError> {
+    read_file(resolve(path, Some(crate_dir))?, Normalize::default())
+}
+
+/// Panic on errors other than `ReaderError::Empty`, which gives an empty `Reader`.
+fn empty_or_panic(result: Result<Reader, ReaderError>) -> Reader {
+    match result {
+        Err(ReaderError::Empty { path }) => Reader::new(String::new(), Some(path)),
+        result => result.unwrap_or_else(|err| panic!("{}", err)),
     }
 }
+
+fn read_file(path: std::path::PathBuf, normalize: Normalize) -> Result<Reader, ReaderError> {
+    let path = path.to_string_lossy();
+
+    let mut text = String::new();
+    InputFile::open(&path)?
+        .read_to_string(&mut text)
+        .map_err(|source| ReaderError::Io {
+            path: path.to_string(),
+            source,
+        })?;
+
+    Reader::non_empty(text, &path, normalize)
+}
+
+/// Read standard input until its end and return the contents as a `Reader`.
+///
+/// Panics if standard input can not be read, see `try_stdin`. An empty input
+/// gives an empty `Reader`.
+pub fn stdin() -> Reader {
+    empty_or_panic(try_stdin())
+}
+
+/// Read standard input until its end and return the contents as a `Reader`.
+///
+/// Returns `ReaderError::Io` if it can not be read and `ReaderError::Empty` if
+/// it contains only whitespace.
+pub fn try_stdin() -> Result<Reader, ReaderError> {
+    read_from(std::io::stdin(), STDIN)
+}
+
+fn read_from(mut source: impl Read, path: &str) -> Result<Reader, ReaderError> {
+    let mut text = String::new();
+    source
+        .read_to_string(&mut text)
+        .map_err(|source| ReaderError::Io {
+            path: path.to_string(),
+            source,
+        })?;
+
+    Reader::non_empty(text, path, Normalize::default())
+}
+
+/// Read standard input if the program was given `-` as an argument, so that
+/// solutions can be run as `cat input.txt | day-9 -`. Returns `None` without
+/// the argument, standard input is never read then.
+///
+/// Returns the errors of `try_stdin` when standard input is read.
+/// ## Example
+/// ```rs
+/// let input = reader::piped_stdin()?.unwrap_or_else(|| reader::open("input.txt"));
+/// ```
+pub fn piped_stdin() -> Result<Option<Reader>, ReaderError> {
+    if !std::env::args().skip(1).any(|arg| arg == "-") {
+        return Ok(None);
+    }
+
+    try_stdin().map(Some)
+}
+
+const STDIN: &str = "<stdin>";
+const STRING: &str = "<string>";
+
+impl core::str::FromStr for Reader {
+    type Err = ReaderError;
+
+    /// Create a `Reader` from in-memory content.
+    ///
+    /// Returns `ReaderError::Empty` if the content contains only whitespace.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Reader::non_empty(s.to_string(), STRING, Normalize::default())
+    }
+}
+
+/// Create a `Reader` from in-memory content, empty content gives an empty
+/// `Reader`. Use `str::parse` to get `ReaderError::Empty` instead.
+impl From<String> for Reader {
+    fn from(text: String) -> Self {
+        Reader::new(text, None)
+    }
+}
+
+impl From<&str> for Reader {
+    fn from(text: &str) -> Self {
+        Reader::new(text.to_string(), None)
+    }
+}
+
+/// Create a `Reader` from a file that is included in the binary at compile time.
+/// The path is relative to the calling crate's `Cargo.toml` and defaults to
+/// `input_example.txt`.
+/// ## Example
+/// ```rs
+/// #[cfg(test)]
+/// fn get_test_input() -> reader::Reader {
+///     reader::embedded!()
+/// }
+/// ```
+#[macro_export]
+macro_rules! embedded {
+    () => {
+        $crate::embedded!("input_example.txt")
+    };
+    ($path:literal) => {
+        $crate::Reader::from_embedded(
+            include_str!(concat!(env!("CARGO_MANIFEST_DIR"), "/", $path)),
+            $path,
+        )
+    };
+}
+
+/// Open a file relative to the calling crate's `Cargo.toml` at run time,
+/// defaulting to `input.txt`. The crate directory is captured when the calling
+/// crate is compiled, so the file is found wherever the binary is run from.
+///
+/// Panics like `reader::open_in`, use `reader::try_input!` for a `Result`.
+/// ## Example
+/// ```rs
+/// let input = reader::input!();
+/// let example = reader::input!("input_example.txt");
+/// ```
+#[macro_export]
+macro_rules! input {
+    () => {
+        $crate::input!("input.txt")
+    };
+    ($path:expr) => {
+        $crate::open_in(env!("CARGO_MANIFEST_DIR"), $path)
+    };
+}
+
+/// Fallible version of `reader::input!`, see `reader::try_open_in`.
+#[macro_export]
+macro_rules! try_input {
+    () => {
+        $crate::try_input!("input.txt")
+    };
+    ($path:expr) => {
+        $crate::try_open_in(env!("CARGO_MANIFEST_DIR"), $path)
+    };
+}
+
+/// Parses like `usize`, but with an error that only implements `Debug`.
+#[cfg(test)]
+#[derive(Debug, PartialEq)]
+struct Digit(usize);
+
+#[cfg(test)]
+impl core::str::FromStr for Digit {
+    type Err = ();
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        s.parse().map(Digit).map_err(|_| ())
+    }
+}
+
+#[test]
+fn test_debug_errors() {
+    let input = || Reader::from("1\n2\nx");
+    assert_eq!(
+        Reader::from("1\n2").lines_as::<Digit>(),
+        vec![Digit(1), Digit(2)]
+    );
+    assert_eq!(input().lines_iter_as::<Digit>().take(2).count(), 2);
+
+    let panic = std::panic::catch_unwind(|| input().lines_as::<Digit>()).unwrap_err();
+    let message = panic.downcast_ref::<String>().unwrap();
+    assert!(message.contains("into the given type \"reader::Digit\": ()"));
+}
+
+#[test]
+fn test_open_empty() {
+    let path = std::env::temp_dir().join(format!("reader-empty-{}.txt", std::process::id()));
+    std::fs::write(&path, "\n").unwrap();
+    let path = path.to_string_lossy().to_string();
+
+    assert!(open(&path).lines().is_empty());
+    assert!(matches!(try_open(&path), Err(ReaderError::Empty { .. })));
+    std::fs::remove_file(&path).unwrap();
+}
+
+#[test]
+fn test_empty_input() {
+    // Panicking constructors and `From` give an empty `Reader`.
+    let stdin = empty_or_panic(read_from(&b" \n"[..], STDIN));
+    assert_eq!(stdin.path(), Some(STDIN));
+    assert!(stdin.lines().is_empty());
+    assert!(Reader::from("\n").lines().is_empty());
+    assert!(Reader::from(String::new()).lines().is_empty());
+
+    // Fallible ones return `ReaderError::Empty`.
+    match "".parse::<Reader>() {
+        Err(ReaderError::Empty { path }) => assert_eq!(path, STRING),
+        other => panic!("Expected Empty, got {:?}", other),
+    }
+    match read_from(&b"\n"[..], STDIN) {
+        Err(ReaderError::Empty { path }) => assert_eq!(path, STDIN),
+        other => panic!("Expected Empty, got {:?}", other),
+    }
+    assert!(matches!(
+        Reader::try_from_text(" ", Normalize::default()),
+        Err(ReaderError::Empty { .. })
+    ));
+}
+
+#[test]
+fn test_piped_stdin_without_dash() {
+    // The test binary is not given `-`, so standard input must not be read.
+    assert!(piped_stdin().unwrap().is_none());
+}
+
+#[test]
+fn test_input_macro() {
+    let input = try_input!("Cargo.toml").unwrap();
+    let path = std::path::Path::new(input.path().unwrap());
+    assert_eq!(
+        path,
+        std::path::Path::new(env!("CARGO_MANIFEST_DIR")).join("Cargo.toml")
+    );
+}
+
+#[test]
+fn test_ragged_grid() {
+    let input = Reader::from_embedded("#..\n.#.\n..\n...", "map.txt");
+    match input.try_grid::<char>() {
+        Err(ReaderError::Ragged {
+            expected,
+            found,
+            location,
+        }) => {
+            assert_eq!((expected, found), (3, 2));
+            assert_eq!((location.line, location.column), (3, 1));
+            assert_eq!(location.path.as_deref(), Some("map.txt"));
+        }
+        other => panic!("Expected a ragged row, got {:?}", other),
+    }
+}
+
+#[test]
+fn test_int_count() {
+    let input = || Reader::from_embedded("x=1, y=-2\nx=3\n", "sensors.txt");
+    assert_eq!(
+        Reader::from("x=1, y=-2").ints_per_line_array::<isize, 2>(),
+        vec![[1, -2]]
+    );
+
+    match input().try_ints_per_line_array::<isize, 2>() {
+        Err(ReaderError::IntCount {
+            expected,
+            found,
+            location,
+        }) => {
+            assert_eq!((expected, found), (2, 1));
+            assert_eq!((location.line, location.column), (2, 1));
+        }
+        other => panic!("Expected a wrong number of integers, got {:?}", other),
+    }
+}
+
+#[test]
+fn test_block_size() {
+    let input = Reader::from_embedded("[1]\n[2]\n\n\n[3]\n[4]\n[5]\n\n[6]\n[7]", "input.txt");
+    match input.try_pairs_of::<String>() {
+        Err(ReaderError::BlockSize {
+            expected,
+            found,
+            location,
+        }) => {
+            assert_eq!((expected, found), (2, 3));
+            assert_eq!(location.block, Some(2));
+            assert_eq!((location.line, location.end_line), (5, 7));
+        }
+        other => panic!("Expected a block of the wrong size, got {:?}", other),
+    }
+
+    let err = Reader::from("1\n2\n\n3\nx")
+        .try_blocks_of::<usize>()
+        .unwrap_err();
+    let location = err.location().unwrap();
+    assert_eq!((location.block, location.line), (Some(2), 5));
+}
@@ -0,0 +1,190 @@
+use std::fmt;
+
+/// Position of a parsed part within the input, rendered like a compiler diagnostic.
+///
+/// ## Example
+/// ```rs
+///  --> input.txt:3:1
+///   |
+/// 3 | abc
+///   | ^^^
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Location {
+    /// Path the input was read from, if any.
+    pub path: Option<String>,
+    /// 1-based line number where the part begins.
+    pub line: usize,
+    /// 1-based line number where the part ends.
+    pub end_line: usize,
+    /// 1-based column (in characters) where the part begins.
+    pub column: usize,
+    /// 1-based block number for parts produced by block-split parsing.
+    pub block: Option<usize>,
+    /// Length of the part in characters. Used to underline single line parts.
+    pub width: usize,
+    /// Source lines from `line` to `end_line`.
+    pub excerpt: Vec<String>,
+    /// Offset of the part in characters from the start of the first excerpt line.
+    pub excerpt_offset: usize,
+}
+
+impl Location {
+    /// Locate `part` within `source`. `part` must be a slice of `source`.
+    pub(crate) fn of(source: &str, part: &str, path: Option<&str>, block: Option<usize>) -> Self {
+        let offset = (part.as_ptr() as usize)
+            .checked_sub(source.as_ptr() as usize)
+            .filter(|offset| offset + part.len() <= source.len())
+            .expect("part is not a slice of the source");
+
+        let before = &source[..offset];
+        let line_start = before.rfind('\n').map_or(0, |index| index + 1);
+        let line_end = source[offset + part.len()..]
+            .find('\n')
+            .map_or(source.len(), |index| offset + part.len() + index);
+
+        let line = before.matches('\n').count() + 1;
+        let column = before[line_start..].chars().count() + 1;
+
+        Location {
+            path: path.map(|path| path.to_string()),
+            line,
+            end_line: line + part.matches('\n').count(),
+            column,
+            block,
+            width: part.chars().count(),
+            excerpt: source[line_start..line_end]
+                .split('\n')
+                .map(|line| line.trim_end_matches('\r').to_string())
+                .collect(),
+            excerpt_offset: column - 1,
+        }
+    }
+
+    /// Move the location down by the given number of lines, for parts of a
+    /// section that begins after the first line of the input.
+    pub(crate) fn shifted(mut self, lines: usize) -> Self {
+        self.line += lines;
+        self.end_line += lines;
+        self
+    }
+
+    /// Location of a part read from a stream, where only the part itself is
+    /// available as the excerpt.
+    pub(crate) fn of_part(
+        part: &str,
+        path: Option<&str>,
+        line: usize,
+        column: usize,
+        block: Option<usize>,
+    ) -> Self {
+        Location {
+            path: path.map(|path| path.to_string()),
+            line,
+            end_line: line + part.matches('\n').count(),
+            column,
+            block,
+            width: part.chars().count(),
+            excerpt: part.split('\n').map(|line| line.to_string()).collect(),
+            excerpt_offset: 0,
+        }
+    }
+}
+
+impl fmt::Display for Location {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let path = self.path.as_deref().unwrap_or("<input>");
+        let gutter = self.end_line.to_string().len();
+        let pad = " ".repeat(gutter);
+
+        if self.line == self.end_line {
+            write!(f, "{}--> {}:{}:{}", pad, path, self.line, self.column)?;
+        } else {
+            write!(f, "{}--> {}:{}-{}", pad, path, self.line, self.end_line)?;
+        }
+        if let Some(block) = self.block {
+            write!(f, " (block {})", block)?;
+        }
+        write!(f, "\n{} |", pad)?;
+
+        if self.excerpt.len() <= 1 {
+            let text = self.excerpt.first().map_or("", |line| line.as_str());
+            write!(
+                f,
+                "\n{:>gutter$} | {}\n{} | {}{}",
+                self.line,
+                text,
+                pad,
+                " ".repeat(self.excerpt_offset),
+                "^".repeat(self.width.max(1)),
+                gutter = gutter
+            )
+        } else {
+            for (index, text) in self.excerpt.iter().enumerate() {
+                let marker = if index == 0 { '/' } else { '|' };
+                write!(
+                    f,
+                    "\n{:>gutter$} | {} {}",
+                    self.line + index,
+                    marker,
+                    text,
+                    gutter = gutter
+                )?;
+            }
+            let last = self.excerpt.last().map_or(0, |line| line.chars().count());
+            write!(f, "\n{} | |{}^", pad, "_".repeat(last))
+        }
+    }
+}
+
+#[test]
+fn test_display_line() {
+    let source = "1\n2x\n3";
+    let location = Location::of(source, &source[2..4], Some("input.txt"), None);
+    assert_eq!(
+        location.to_string(),
+        [" --> input.txt:2:1", "  |", "2 | 2x", "  | ^^"].join("\n")
+    );
+
+    let location = Location::of(source, &source[3..4], None, None);
+    assert_eq!(
+        location.to_string(),
+        [" --> <input>:2:2", "  |", "2 | 2x", "  |  ^"].join("\n")
+    );
+}
+
+#[test]
+fn test_display_block() {
+    let source = "1\n2\n\n3\nx";
+    let location = Location::of(source, &source[5..], None, Some(2));
+    assert_eq!(
+        location.to_string(),
+        [
+            " --> <input>:4-5 (block 2)",
+            "  |",
+            "4 | / 3",
+            "5 | | x",
+            "  | |_^"
+        ]
+        .join("\n")
+    );
+}
+
+#[test]
+fn test_display_shifted() {
+    let source = "1\n2x\n3";
+    let location = Location::of(source, &source[2..4], Some("input.txt"), None).shifted(9);
+    assert_eq!(
+        location.to_string(),
+        ["  --> input.txt:11:1", "   |", "11 | 2x", "   | ^^"].join("\n")
+    );
+}
+
+#[test]
+fn test_display_stream() {
+    let location = Location::of_part("12", Some("input.txt"), 3, 5, None);
+    assert_eq!(
+        location.to_string(),
+        [" --> input.txt:3:5", "  |", "3 | 12", "  | ^^"].join("\n")
+    );
+}
@@ -0,0 +1,356 @@
+use crate::{Location, ReaderError};
+use std::io::{self, BufRead, Read};
+
+const BOM: char = '\u{feff}';
+
+const BOM_ISSUE: &str = "Input begins with a byte order mark";
+const NEWLINE_ISSUE: &str = "Input has \\r\\n line changes";
+const BLANK_ISSUE: &str = "Line contains only whitespace";
+const TRAILING_ISSUE: &str = "Input ends with empty lines";
+
+/// How the content of a `Reader` is cleaned up when it is created.
+///
+/// Every constructor applies `Normalize::default()`, which enables all repairs.
+/// Use `open_with`, `stream_with`, `Reader::try_from_text` or
+/// `StreamReader::with_normalize` to choose other options.
+/// The final line change is always removed when `trailing_blank_lines` is set.
+/// ## Example
+/// ```
+/// use reader::{Normalize, Reader};
+///
+/// let input = Reader::try_from_text("1,2,3\r\n\r\n", Normalize::default()).unwrap();
+/// assert_eq!(input.split_line_into::<usize>(","), vec![1, 2, 3]);
+///
+/// let err = Reader::try_from_text("1,2,3\r\n", Normalize::strict()).unwrap_err();
+/// assert_eq!(err.location().unwrap().line, 1);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Normalize {
+    /// Replace `\r\n` line changes by `\n`.
+    pub newlines: bool,
+    /// Remove a byte order mark from the start of the content.
+    pub bom: bool,
+    /// Turn lines that contain only spaces and tabs into empty lines, so that they separate blocks.
+    pub blank_lines: bool,
+    /// Remove empty lines and the line change after the last line with content.
+    pub trailing_blank_lines: bool,
+    /// Return `ReaderError::Malformed` for content that one of the enabled
+    /// options would change, instead of changing it. A single final line change
+    /// is not considered malformed.
+    pub strict: bool,
+}
+
+impl Default for Normalize {
+    fn default() -> Self {
+        Normalize {
+            newlines: true,
+            bom: true,
+            blank_lines: true,
+            trailing_blank_lines: true,
+            strict: false,
+        }
+    }
+}
+
+impl Normalize {
+    /// All options enabled in strict mode.
+    pub fn strict() -> Self {
+        Normalize {
+            strict: true,
+            ..Normalize::default()
+        }
+    }
+
+    /// No options enabled, the content is used as is.
+    pub fn none() -> Self {
+        Normalize {
+            newlines: false,
+            bom: false,
+            blank_lines: false,
+            trailing_blank_lines: false,
+            strict: false,
+        }
+    }
+
+    /// Normalize the content, or check it in strict mode.
+    pub(crate) fn apply(&self, text: String, path: Option<&str>) -> Result<String, ReaderError> {
+        if self.strict {
+            self.check(&text, path)?;
+        }
+
+        Ok(self.repair(text))
+    }
+
+    /// Normalize the content. In strict mode the content has already been
+    /// checked, so only the final line change is left to remove.
+    pub(crate) fn repair(&self, mut text: String) -> String {
+        if self.bom && text.starts_with(BOM) {
+            text.drain(..BOM.len_utf8());
+        }
+
+        if self.newlines && text.contains("\r\n") {
+            text = text.replace("\r\n", "\n");
+        }
+
+        if self.blank_lines && text.split('\n').any(is_blank) {
+            text = text
+                .split('\n')
+                .map(|line| if is_blank(line) { "" } else { line })
+                .collect::<Vec<&str>>()
+                .join("\n");
+        }
+
+        if self.trailing_blank_lines {
+            text.truncate(content_end(&text));
+        }
+
+        text
+    }
+
+    /// First part of the content that would be changed by an enabled option.
+    fn check(&self, text: &str, path: Option<&str>) -> Result<(), ReaderError> {
+        let malformed = |issue: &'static str, part: &str| ReaderError::Malformed {
+            issue,
+            location: Box::new(Location::of(text, part, path, None)),
+        };
+
+        if self.bom && text.starts_with(BOM) {
+            return Err(malformed(BOM_ISSUE, &text[..BOM.len_utf8()]));
+        }
+
+        if self.newlines {
+            if let Some(index) = text.find("\r\n") {
+                return Err(malformed(NEWLINE_ISSUE, &text[index..index + 1]));
+            }
+        }
+
+        if self.blank_lines {
+            if let Some(line) = text.split('\n').find(|line| is_blank(line)) {
+                return Err(malformed(BLANK_ISSUE, line));
+            }
+        }
+
+        if self.trailing_blank_lines {
+            let end = content_end(text);
+            let trailing = text[end..].strip_prefix('\n').unwrap_or(&text[end..]);
+            if !trailing.is_empty() {
+                let lines = trailing.strip_suffix('\n').unwrap_or(trailing);
+                return Err(malformed(TRAILING_ISSUE, lines));
+            }
+        }
+
+        Ok(())
+    }
+}
+
+/// `BufRead` adapter that applies a `Normalize` to its input one line at a time,
+/// so that a `StreamReader` sees the same content as a `Reader` would.
+///
+/// Lines that contain only whitespace are held back until a line with content
+/// follows them, and dropped together with the final line change at the end of
+/// the input. In strict mode reading fails with `InvalidData` and the
+/// `ReaderError::Malformed` is left in `malformed`.
+pub(crate) struct Normalized<R> {
+    inner: R,
+    normalize: Normalize,
+    path: Option<String>,
+    /// Normalized content that is ready to be read.
+    buf: Vec<u8>,
+    pos: usize,
+    /// Held back line change and whitespace-only lines.
+    pending: Vec<u8>,
+    /// Line number of the first whitespace-only line in `pending`.
+    pending_line: usize,
+    /// Line number of the next line of the input.
+    line: usize,
+    pub(crate) malformed: Option<ReaderError>,
+}
+
+impl<R: BufRead> Normalized<R> {
+    pub(crate) fn new(inner: R, normalize: Normalize, path: Option<String>) -> Self {
+        Normalized {
+            inner,
+            normalize,
+            path,
+            buf: vec![],
+            pos: 0,
+            pending: vec![],
+            pending_line: 1,
+            line: 1,
+            malformed: None,
+        }
+    }
+
+    /// Normalize the next line of the input into `buf`. Returns `false` at the
+    /// end of the input.
+    fn fill(&mut self) -> io::Result<bool> {
+        self.buf.clear();
+        self.pos = 0;
+
+        let mut line = vec![];
+        if self.inner.read_until(b'\n', &mut line)? == 0 {
+            let trailing = self.pending.strip_prefix(b"\n").unwrap_or(&self.pending);
+            if self.normalize.strict && !trailing.is_empty() {
+                let trailing = trailing.strip_suffix(b"\n").unwrap_or(trailing);
+                let trailing = String::from_utf8_lossy(trailing).to_string();
+                return Err(self.malformed(TRAILING_ISSUE, &trailing, self.pending_line, 1));
+            }
+            return Ok(false);
+        }
+
+        let number = self.line;
+        self.line += 1;
+        let bom = BOM.to_string();
+        if number == 1 && self.normalize.bom && line.starts_with(bom.as_bytes()) {
+            if self.normalize.strict {
+                return Err(self.malformed(BOM_ISSUE, &bom, number, 1));
+            }
+            line.drain(..bom.len());
+        }
+
+        if self.normalize.newlines && line.ends_with(b"\r\n") {
+            if self.normalize.strict {
+                let column = String::from_utf8_lossy(&line).chars().count() - 1;
+                return Err(self.malformed(NEWLINE_ISSUE, "\r", number, column));
+            }
+            line.remove(line.len() - 2);
+        }
+
+        let end = line.len() - usize::from(line.ends_with(b"\n"));
+        let text = String::from_utf8_lossy(&line[..end]).to_string();
+        if self.normalize.blank_lines && is_blank(&text) {
+            if self.normalize.strict {
+                return Err(self.malformed(BLANK_ISSUE, &text, number, 1));
+            }
+            line.drain(..end);
+        }
+
+        if !self.normalize.trailing_blank_lines {
+            self.buf = line;
+        } else if text.trim().is_empty() {
+            if self.pending.is_empty() || self.pending == b"\n" {
+                self.pending_line = number;
+            }
+            self.pending.append(&mut line);
+        } else {
+            // Hold back the line change until the next line with content.
+            let end = line.len() - usize::from(line.ends_with(b"\n"));
+            self.buf.append(&mut self.pending);
+            self.buf.extend_from_slice(&line[..end]);
+            self.pending.extend_from_slice(&line[end..]);
+        }
+        Ok(true)
+    }
+
+    fn malformed(
+        &mut self,
+        issue: &'static str,
+        part: &str,
+        line: usize,
+        column: usize,
+    ) -> io::Error {
+        self.malformed = Some(ReaderError::Malformed {
+            issue,
+            location: Box::new(Location::of_part(
+                part,
+                self.path.as_deref(),
+                line,
+                column,
+                None,
+            )),
+        });
+        io::Error::new(io::ErrorKind::InvalidData, issue)
+    }
+}
+
+impl<R: BufRead> Read for Normalized<R> {
+    fn read(&mut self, out: &mut [u8]) -> io::Result<usize> {
+        let available = self.fill_buf()?;
+        let count = available.len().min(out.len());
+        out[..count].copy_from_slice(&available[..count]);
+        self.consume(count);
+        Ok(count)
+    }
+}
+
+impl<R: BufRead> BufRead for Normalized<R> {
+    fn fill_buf(&mut self) -> io::Result<&[u8]> {
+        while self.pos == self.buf.len() {
+            if !self.fill()? {
+                break;
+            }
+        }
+        Ok(&self.buf[self.pos..])
+    }
+
+    fn consume(&mut self, amount: usize) {
+        self.pos += amount;
+    }
+}
+
+/// Whether the line is not empty but contains only spaces and tabs.
+fn is_blank(line: &str) -> bool {
+    !line.is_empty() && line.chars().all(|char| char == ' ' || char == '\t')
+}
+
+/// End of the last line that contains something else than whitespace.
+fn content_end(text: &str) -> usize {
+    match text.rfind(|char: char| !char.is_whitespace()) {
+        Some(index) => text[index..]
+            .find('\n')
+            .map_or(text.len(), |end| index + end),
+        None => 0,
+    }
+}
+
+#[cfg(test)]
+fn normalized(text: &str, normalize: Normalize) -> String {
+    crate::Reader::try_from_text(text, normalize)
+        .unwrap()
+        .text()
+}
+
+#[test]
+fn test_reader_repairs() {
+    let only = |option: fn(&mut Normalize)| {
+        let mut normalize = Normalize::none();
+        option(&mut normalize);
+        normalize
+    };
+
+    let newlines = only(|normalize| normalize.newlines = true);
+    assert_eq!(normalized("1\r\n2\r\n", newlines), "1\n2\n");
+    assert_eq!(normalized("1\r\n2\r\n", Normalize::none()), "1\r\n2\r\n");
+
+    let bom = only(|normalize| normalize.bom = true);
+    assert_eq!(normalized("\u{feff}1\n2", bom), "1\n2");
+    assert_eq!(normalized("\u{feff}1", Normalize::none()), "\u{feff}1");
+
+    let blank_lines = only(|normalize| normalize.blank_lines = true);
+    assert_eq!(normalized("1\n \t\n2", blank_lines), "1\n\n2");
+    assert_eq!(normalized("1\n \n2", Normalize::none()), "1\n \n2");
+
+    let trailing = only(|normalize| normalize.trailing_blank_lines = true);
+    assert_eq!(normalized("1\n2\n\n\n", trailing), "1\n2");
+    assert_eq!(normalized("1\n\n2\n", trailing), "1\n\n2");
+
+    let all = "\u{feff}1\r\n \r\n2\r\n\r\n";
+    assert_eq!(normalized(all, Normalize::default()), "1\n\n2");
+}
+
+#[test]
+fn test_reader_strict() {
+    let malformed = |text: &str| match crate::Reader::try_from_text(text, Normalize::strict()) {
+        Err(ReaderError::Malformed { issue, location }) => (issue, location.line, location.column),
+        Err(err) => panic!("Expected malformed content, got {}", err),
+        Ok(_) => panic!("Expected {:?} to be malformed", text),
+    };
+
+    assert_eq!(malformed("\u{feff}1\n2"), (BOM_ISSUE, 1, 1));
+    assert_eq!(malformed("1\n2\r\n3"), (NEWLINE_ISSUE, 2, 2));
+    assert_eq!(malformed("1\n2\n \n3"), (BLANK_ISSUE, 3, 1));
+    assert_eq!(malformed("1\n2\n\n\n"), (TRAILING_ISSUE, 3, 1));
+
+    // A single final line change is not malformed.
+    assert_eq!(normalized("1\n\n2\n", Normalize::strict()), "1\n\n2");
+}
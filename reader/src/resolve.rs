@@ -0,0 +1,161 @@
+use crate::ReaderError;
+use std::{
+    env,
+    path::{Component, Path, PathBuf},
+};
+
+/// Environment variable naming a directory that puzzle inputs are looked up from first.
+pub const INPUT_DIR_VAR: &str = "AOC_INPUT_DIR";
+
+/// Locations searched for the given path, in order.
+///
+/// Absolute paths and paths beginning with `.` or `..` are explicit and
+/// used as is. Other paths are looked up from
+/// 1. the directory in the `AOC_INPUT_DIR` environment variable. With a crate
+///    directory the subdirectory of the same name is used, such as
+///    `$AOC_INPUT_DIR/day-9` for `day-9`, so every crate has its own inputs,
+/// 2. the given crate directory, which `reader::input!` captures from the
+///    calling crate's `CARGO_MANIFEST_DIR` at compile time,
+/// 3. the current working directory.
+pub fn candidates(path: &str, crate_dir: Option<&str>) -> Vec<PathBuf> {
+    search(
+        path,
+        [
+            input_dir(env::var_os(INPUT_DIR_VAR).map(PathBuf::from), crate_dir),
+            crate_dir.map(PathBuf::from),
+            Some(env::current_dir().unwrap_or_default()),
+        ],
+    )
+}
+
+/// Find the first existing file from the `candidates` of the given path.
+///
+/// Returns `ReaderError::NotFound` listing every location tried if none exists.
+pub fn resolve(path: &str, crate_dir: Option<&str>) -> Result<PathBuf, ReaderError> {
+    find(path, candidates(path, crate_dir))
+}
+
+/// Directory of the crate's inputs within the `AOC_INPUT_DIR` directory.
+fn input_dir(dir: Option<PathBuf>, crate_dir: Option<&str>) -> Option<PathBuf> {
+    let dir = dir?;
+    match crate_dir.and_then(|crate_dir| Path::new(crate_dir).file_name()) {
+        Some(name) => Some(dir.join(name)),
+        None => Some(dir),
+    }
+}
+
+/// The given path joined to each of the directories, or the path alone if it is explicit.
+fn search(path: &str, dirs: impl IntoIterator<Item = Option<PathBuf>>) -> Vec<PathBuf> {
+    let relative = Path::new(path);
+
+    let explicit = relative.is_absolute()
+        || matches!(
+            relative.components().next(),
+            Some(Component::CurDir | Component::ParentDir)
+        );
+    if explicit {
+        return vec![relative.to_path_buf()];
+    }
+
+    let mut res: Vec<PathBuf> = dirs
+        .into_iter()
+        .flatten()
+        .map(|dir| dir.join(relative))
+        .collect();
+    res.dedup();
+
+    res
+}
+
+fn find(path: &str, tried: Vec<PathBuf>) -> Result<PathBuf, ReaderError> {
+    match tried.iter().find(|candidate| candidate.is_file()) {
+        Some(found) => Ok(found.clone()),
+        None => Err(ReaderError::NotFound {
+            path: path.to_string(),
+            tried,
+        }),
+    }
+}
+
+#[cfg(test)]
+fn temp_dirs(name: &str) -> [PathBuf; 3] {
+    let root = env::temp_dir().join(format!("reader-{}-{}", name, std::process::id()));
+    ["inputs", "crate", "cwd"].map(|dir| {
+        let dir = root.join(dir);
+        std::fs::create_dir_all(&dir).unwrap();
+        dir
+    })
+}
+
+#[test]
+fn test_search_order() {
+    let dirs = temp_dirs("search");
+    let [inputs, krate, cwd] = dirs.clone();
+    let search_all = |path| search(path, dirs.clone().map(Some));
+
+    assert_eq!(
+        search_all("input.txt"),
+        vec![
+            inputs.join("input.txt"),
+            krate.join("input.txt"),
+            cwd.join("input.txt")
+        ]
+    );
+    assert_eq!(
+        search_all("./input.txt"),
+        vec![PathBuf::from("./input.txt")]
+    );
+    assert_eq!(
+        search("a.txt", [None, Some(krate.clone()), Some(krate.clone())]),
+        vec![krate.join("a.txt")]
+    );
+
+    std::fs::write(cwd.join("input.txt"), "cwd").unwrap();
+    assert_eq!(
+        find("input.txt", search_all("input.txt")).unwrap(),
+        cwd.join("input.txt")
+    );
+    std::fs::write(krate.join("input.txt"), "crate").unwrap();
+    assert_eq!(
+        find("input.txt", search_all("input.txt")).unwrap(),
+        krate.join("input.txt")
+    );
+
+    std::fs::remove_dir_all(inputs.parent().unwrap()).unwrap();
+}
+
+#[test]
+fn test_not_found() {
+    let dirs = temp_dirs("missing");
+    let tried = search("missing.txt", dirs.clone().map(Some));
+
+    match find("missing.txt", tried.clone()) {
+        Err(err @ ReaderError::NotFound { .. }) => {
+            let message = err.to_string();
+            assert!(message.starts_with("Unable to find \"missing.txt\", tried:"));
+            for candidate in tried.iter() {
+                assert!(message.contains(&format!("\n\t{}", candidate.display())));
+            }
+            assert!(matches!(err, ReaderError::NotFound { tried: found, .. } if found == tried));
+        }
+        other => panic!("expected NotFound, got {:?}", other),
+    }
+
+    std::fs::remove_dir_all(dirs[0].parent().unwrap()).unwrap();
+}
+
+#[test]
+fn test_input_dir() {
+    let inputs = Some(PathBuf::from("/inputs"));
+
+    assert_eq!(
+        input_dir(inputs.clone(), Some("/repo/day-9")),
+        Some(PathBuf::from("/inputs/day-9"))
+    );
+    assert_eq!(
+        input_dir(inputs.clone(), Some("/repo/day-10/")),
+        Some(PathBuf::from("/inputs/day-10"))
+    );
+    assert_eq!(input_dir(inputs, None), Some(PathBuf::from("/inputs")));
+    assert_eq!(input_dir(None, Some("/repo/day-9")), None);
+}
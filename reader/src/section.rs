@@ -0,0 +1,103 @@
+use crate::{BoxError, Grid, Reader, ReaderError};
+use std::str::FromStr;
+
+/// Conversion from one section of the input, as produced by `Reader::sections`.
+///
+/// The section is given as a `Reader`, so implementations can use any of its
+/// parsing methods. Errors keep the line numbers of the original input.
+/// ## Example
+/// ```
+/// use reader::{FromSection, Reader, ReaderError};
+///
+/// struct Drawing(Vec<String>);
+///
+/// impl FromSection for Drawing {
+///     fn from_section(section: Reader) -> Result<Self, ReaderError> {
+///         Ok(Drawing(section.lines()))
+///     }
+/// }
+///
+/// let input: Reader = "#.\n.#\n\n1\n2".parse().unwrap();
+/// let (drawing, numbers): (Drawing, Vec<usize>) = input.sections2();
+/// assert_eq!((drawing.0.len(), numbers), (2, vec![1, 2]));
+/// ```
+pub trait FromSection: Sized {
+    fn from_section(section: Reader) -> Result<Self, ReaderError>;
+}
+
+impl FromSection for Reader {
+    fn from_section(section: Reader) -> Result<Self, ReaderError> {
+        Ok(section)
+    }
+}
+
+impl FromSection for String {
+    fn from_section(section: Reader) -> Result<Self, ReaderError> {
+        Ok(section.text())
+    }
+}
+
+/// Each line of the section parsed into `T`.
+impl<T> FromSection for Vec<T>
+where
+    T: FromStr,
+    T::Err: Into<BoxError>,
+{
+    fn from_section(section: Reader) -> Result<Self, ReaderError> {
+        section.try_lines_as()
+    }
+}
+
+/// Each character of the section parsed into `T`.
+impl<T> FromSection for Grid<T>
+where
+    T: FromStr,
+    T::Err: Into<BoxError>,
+{
+    fn from_section(section: Reader) -> Result<Self, ReaderError> {
+        section.try_grid()
+    }
+}
+
+/// Parse the section with the given 1-based index, naming the section on failure.
+pub(crate) fn parse_section<T: FromSection>(
+    index: usize,
+    section: Reader,
+) -> Result<T, ReaderError> {
+    T::from_section(section).map_err(|source| ReaderError::Section {
+        index,
+        type_name: std::any::type_name::<T>(),
+        source: Box::new(source),
+    })
+}
+
+#[test]
+fn test_section_count() {
+    let input = Reader::from_embedded("1\n2\n\n3", "input.txt");
+    match input.try_sections3::<Vec<usize>, Vec<usize>, Vec<usize>>() {
+        Err(ReaderError::SectionCount {
+            path,
+            expected,
+            found,
+        }) => {
+            assert_eq!(path.as_deref(), Some("input.txt"));
+            assert_eq!((expected, found), (3, 2));
+        }
+        Err(err) => panic!("Expected a wrong number of sections, got {}", err),
+        Ok(_) => panic!("Expected a wrong number of sections"),
+    }
+}
+
+#[test]
+fn test_section_error() {
+    let input = Reader::from_embedded("#.\n.#\n\n1\n2\n\n3\nx", "input.txt");
+    let err = input
+        .try_sections3::<Grid<char>, Vec<usize>, Vec<usize>>()
+        .err()
+        .unwrap();
+
+    assert!(matches!(err, ReaderError::Section { index: 3, .. }));
+    let location = err.location().unwrap();
+    assert_eq!((location.line, location.column), (8, 1));
+    assert_eq!(location.excerpt, vec!["x"]);
+}
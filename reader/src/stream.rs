@@ -0,0 +1,616 @@
+use crate::{
+    error::Lenient, iter::Split, normalize::Normalized, resolve, BoxError, InputFile, Location,
+    Normalize, ReaderError,
+};
+use std::{fmt::Debug, io::BufRead, marker::PhantomData, str::FromStr};
+
+/// Reader that parses its input incrementally from a `BufRead` instead of
+/// loading the whole content into memory.
+///
+/// Provides the same parsing methods as `Reader`, but each of them returns an
+/// iterator that reads only as much input as is needed for the next part.
+/// Use `reader::stream(path: &str)` to create a new `StreamReader` from a text file.
+///
+/// The content is normalized while it is read like the content of a `Reader`,
+/// see `Normalize`. Lines that contain only whitespace are held back until a
+/// line with content follows them.
+/// ## Example
+/// ```rs
+/// // moves.txt (several gigabytes)
+/// // R 4
+/// // U 4
+/// // ...
+/// stream("moves.txt").lines().filter(|line| line.starts_with('R')).count()
+/// ```
+pub struct StreamReader<R> {
+    inner: R,
+    path: Option<String>,
+    normalize: Normalize,
+}
+
+impl<R: BufRead> StreamReader<R> {
+    /// Create a `StreamReader` reading from the given source, normalized with
+    /// `Normalize::default()`.
+    pub fn new(inner: R) -> Self {
+        StreamReader::with_normalize(inner, Normalize::default())
+    }
+
+    /// Create a `StreamReader` reading from the given source with the given
+    /// normalization. In strict mode the parts are `ReaderError::Malformed`
+    /// from the first content the normalization would change.
+    pub fn with_normalize(inner: R, normalize: Normalize) -> Self {
+        StreamReader {
+            inner,
+            path: None,
+            normalize,
+        }
+    }
+
+    /// Path of the file the content is read from, if any.
+    pub fn path(&self) -> Option<&str> {
+        self.path.as_deref()
+    }
+
+    /// Content lines read one at a time. `\r\n` line changes are accepted.
+    ///
+    /// Panics if the input can not be read, see `try_lines`.
+    pub fn lines(self) -> impl Iterator<Item = String> {
+        self.try_lines().map(unwrap_or_panic)
+    }
+
+    /// Fallible version of `lines`.
+    pub fn try_lines(self) -> impl Iterator<Item = Result<String, ReaderError>> {
+        self.chunks(Split::Lines)
+            .map(|chunk| chunk.map(|chunk| chunk.text))
+    }
+
+    /// Content blocks divided by empty lines, read one block at a time.
+    /// Consecutive empty lines are treated as a single separator.
+    ///
+    /// Panics if the input can not be read, see `try_split_on_empty_line`.
+    pub fn split_on_empty_line(self) -> impl Iterator<Item = String> {
+        self.try_split_on_empty_line().map(unwrap_or_panic)
+    }
+
+    /// Fallible version of `split_on_empty_line`.
+    pub fn try_split_on_empty_line(self) -> impl Iterator<Item = Result<String, ReaderError>> {
+        self.chunks(Split::Blocks)
+            .map(|chunk| chunk.map(|chunk| chunk.text))
+    }
+
+    /// Content split on the given pattern, read one part at a time.
+    ///
+    /// Panics if the pattern is empty or the input can not be read.
+    pub fn split_line(self, pattern: &str) -> impl Iterator<Item = String> {
+        self.try_split_line(pattern).map(unwrap_or_panic)
+    }
+
+    /// Fallible version of `split_line`.
+    ///
+    /// Panics if the pattern is empty.
+    pub fn try_split_line(
+        self,
+        pattern: &str,
+    ) -> impl Iterator<Item = Result<String, ReaderError>> {
+        self.chunks(Split::Pattern(pattern.to_string()))
+            .map(|chunk| chunk.map(|chunk| chunk.text))
+    }
+
+    /// Content lines parsed into the given type one at a time. See `Reader::lines_as`.
+    ///
+    /// Panics if the input can not be read or a line can not be parsed.
+    pub fn lines_as<T>(self) -> impl Iterator<Item = T>
+    where
+        T: FromStr,
+        T::Err: Debug,
+    {
+        self.try_lines_as()
+            .map(|parsed: Result<Lenient<T>, ReaderError>| unwrap_or_panic(parsed).0)
+    }
+
+    /// Fallible version of `lines_as`. The iterator stops after the first error.
+    pub fn try_lines_as<T>(self) -> StreamParse<R, T>
+    where
+        T: FromStr,
+        T::Err: Into<BoxError>,
+    {
+        StreamParse::new(self.chunks(Split::Lines))
+    }
+
+    /// Content blocks parsed into the given type one at a time.
+    /// See `Reader::split_on_empty_line_into`.
+    ///
+    /// Panics if the input can not be read or a block can not be parsed.
+    pub fn split_on_empty_line_into<T>(self) -> impl Iterator<Item = T>
+    where
+        T: FromStr,
+        T::Err: Debug,
+    {
+        self.try_split_on_empty_line_into()
+            .map(|parsed: Result<Lenient<T>, ReaderError>| unwrap_or_panic(parsed).0)
+    }
+
+    /// Fallible version of `split_on_empty_line_into`. The iterator stops after the first error.
+    pub fn try_split_on_empty_line_into<T>(self) -> StreamParse<R, T>
+    where
+        T: FromStr,
+        T::Err: Into<BoxError>,
+    {
+        StreamParse::new(self.chunks(Split::Blocks))
+    }
+
+    /// Content split on the given pattern and parsed into the given type one part
+    /// at a time. See `Reader::split_line_into`.
+    ///
+    /// Panics if the pattern is empty, the input can not be read or a part can not be parsed.
+    pub fn split_line_into<T>(self, pattern: &str) -> impl Iterator<Item = T>
+    where
+        T: FromStr,
+        T::Err: Debug,
+    {
+        self.try_split_line_into(pattern)
+            .map(|parsed: Result<Lenient<T>, ReaderError>| unwrap_or_panic(parsed).0)
+    }
+
+    /// Fallible version of `split_line_into`. The iterator stops after the first error.
+    ///
+    /// Panics if the pattern is empty.
+    pub fn try_split_line_into<T>(self, pattern: &str) -> StreamParse<R, T>
+    where
+        T: FromStr,
+        T::Err: Into<BoxError>,
+    {
+        StreamParse::new(self.chunks(Split::Pattern(pattern.to_string())))
+    }
+
+    /// Content lines passed through the given function one at a time.
+    /// See `Reader::parse_lines`.
+    ///
+    /// Panics if the input can not be read.
+    pub fn parse_lines<T>(self, mut f: impl FnMut(&str) -> T) -> impl Iterator<Item = T> {
+        self.lines().map(move |line| f(&line))
+    }
+
+    fn chunks(self, split: Split) -> Chunks<R> {
+        if let Split::Pattern(pattern) = &split {
+            assert!(!pattern.is_empty(), "Split pattern must not be empty.");
+        }
+
+        Chunks {
+            inner: Normalized::new(self.inner, self.normalize, self.path.clone()),
+            path: self.path,
+            split,
+            line: 1,
+            column: 1,
+            block: 0,
+            finished: false,
+        }
+    }
+}
+
+/// Open text file in the given path as a `StreamReader`.
+///
+/// Panics if the file can not be opened or is empty, see `try_stream`.
+pub fn stream(path: &str) -> StreamReader<InputFile> {
+    try_stream(path).unwrap_or_else(|err| panic!("{}", err))
+}
+
+/// Open text file in the given path as a `StreamReader` with the given normalization.
+///
+/// Panics if the file can not be opened or is empty, see `try_stream`.
+pub fn stream_with(path: &str, normalize: Normalize) -> StreamReader<InputFile> {
+    try_stream_with(path, normalize).unwrap_or_else(|err| panic!("{}", err))
+}
+
+/// Open text file in the given path as a `StreamReader`.
+/// The content is normalized with `Normalize::default()` while it is read.
+/// The path is resolved like in `reader::open`. Compressed files are
+/// decompressed as they are read, so they are never expanded fully in memory.
+///
+/// Returns `ReaderError::NotFound` if the file does not exist, `ReaderError::Io`
+/// if it can not be opened, `ReaderError::Compressed` if it is compressed without
+/// the `compression` feature and `ReaderError::Empty` if it has no content.
+pub fn try_stream(path: &str) -> Result<StreamReader<InputFile>, ReaderError> {
+    try_stream_with(path, Normalize::default())
+}
+
+/// Fallible version of `stream_with`.
+pub fn try_stream_with(
+    path: &str,
+    normalize: Normalize,
+) -> Result<StreamReader<InputFile>, ReaderError> {
+    let path = resolve(path, None)?;
+    let path = path.to_string_lossy();
+
+    let mut inner = InputFile::open(&path)?;
+    let start = inner.fill_buf().map_err(|source| ReaderError::Io {
+        path: path.to_string(),
+        source,
+    })?;
+    if start.is_empty() {
+        return Err(ReaderError::Empty {
+            path: path.to_string(),
+        });
+    }
+
+    Ok(StreamReader {
+        inner,
+        path: Some(path.to_string()),
+        normalize,
+    })
+}
+
+fn unwrap_or_panic<T>(result: Result<T, ReaderError>) -> T {
+    result.unwrap_or_else(|err| panic!("{}", err))
+}
+
+/// Part of the input together with where it began.
+struct Chunk {
+    text: String,
+    line: usize,
+    column: usize,
+    block: Option<usize>,
+}
+
+/// Iterator reading the next part of the input on demand.
+struct Chunks<R> {
+    inner: Normalized<R>,
+    path: Option<String>,
+    split: Split,
+    /// Line and column where the next part begins.
+    line: usize,
+    column: usize,
+    block: usize,
+    finished: bool,
+}
+
+impl<R: BufRead> Chunks<R> {
+    /// Error for a failed read, which is `ReaderError::Malformed` if strict
+    /// normalization rejected the input.
+    fn io_error(&mut self, source: std::io::Error) -> ReaderError {
+        self.inner
+            .malformed
+            .take()
+            .unwrap_or_else(|| ReaderError::Io {
+                path: self.path.clone().unwrap_or_else(|| "<stream>".to_string()),
+                source,
+            })
+    }
+
+    /// Read the next line without its line change, or `None` at the end of input.
+    fn read_line(&mut self) -> Result<Option<String>, ReaderError> {
+        let mut line = String::new();
+        if self
+            .inner
+            .read_line(&mut line)
+            .map_err(|err| self.io_error(err))?
+            == 0
+        {
+            return Ok(None);
+        }
+
+        if line.ends_with('\n') {
+            line.pop();
+            if line.ends_with('\r') {
+                line.pop();
+            }
+        }
+        Ok(Some(line))
+    }
+
+    fn next_line(&mut self) -> Result<Option<Chunk>, ReaderError> {
+        let line = self.line;
+        Ok(self.read_line()?.map(|text| {
+            self.line += 1;
+            Chunk {
+                text,
+                line,
+                column: 1,
+                block: None,
+            }
+        }))
+    }
+
+    fn next_block(&mut self) -> Result<Option<Chunk>, ReaderError> {
+        let mut lines: Vec<String> = vec![];
+        let mut first_line = self.line;
+
+        while let Some(line) = self.read_line()? {
+            self.line += 1;
+            if !line.is_empty() {
+                lines.push(line);
+            } else if lines.is_empty() {
+                first_line = self.line;
+            } else {
+                break;
+            }
+        }
+
+        if lines.is_empty() {
+            return Ok(None);
+        }
+
+        self.block += 1;
+        Ok(Some(Chunk {
+            text: lines.join("\n"),
+            line: first_line,
+            column: 1,
+            block: Some(self.block),
+        }))
+    }
+
+    fn next_part(&mut self, pattern: &str) -> Result<Option<Chunk>, ReaderError> {
+        let separator = pattern.as_bytes();
+        let last = separator[separator.len() - 1];
+        let mut bytes = vec![];
+
+        loop {
+            let read = self
+                .inner
+                .read_until(last, &mut bytes)
+                .map_err(|err| self.io_error(err))?;
+
+            if bytes.ends_with(separator) {
+                bytes.truncate(bytes.len() - separator.len());
+                break;
+            }
+            if read == 0 {
+                self.finished = true;
+                break;
+            }
+        }
+
+        let text = String::from_utf8(bytes).map_err(|err| {
+            self.io_error(std::io::Error::new(std::io::ErrorKind::InvalidData, err))
+        })?;
+
+        let chunk = Chunk {
+            line: self.line,
+            column: self.column,
+            block: None,
+            text,
+        };
+
+        // Advance the position past the part and the separator.
+        for consumed in [chunk.text.as_str(), pattern] {
+            match consumed.rfind('\n') {
+                Some(index) => {
+                    self.line += consumed.matches('\n').count();
+                    self.column = consumed[index + 1..].chars().count() + 1;
+                }
+                None => self.column += consumed.chars().count(),
+            }
+        }
+
+        Ok(Some(chunk))
+    }
+}
+
+impl<R: BufRead> Iterator for Chunks<R> {
+    type Item = Result<Chunk, ReaderError>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.finished {
+            return None;
+        }
+
+        let next = match &self.split {
+            Split::Lines => self.next_line(),
+            Split::Blocks => self.next_block(),
+            Split::Pattern(pattern) => {
+                let pattern = pattern.clone();
+                self.next_part(&pattern)
+            }
+        };
+
+        match next {
+            Ok(Some(chunk)) => Some(Ok(chunk)),
+            Ok(None) => {
+                self.finished = true;
+                None
+            }
+            Err(err) => {
+                self.finished = true;
+                Some(Err(err))
+            }
+        }
+    }
+}
+
+/// Iterator parsing parts of a `StreamReader` into `T` as they are read.
+///
+/// Yields `Err` for the first part that can not be read or parsed and stops afterwards.
+pub struct StreamParse<R, T> {
+    chunks: Chunks<R>,
+    marker: PhantomData<fn() -> T>,
+}
+
+impl<R, T> StreamParse<R, T> {
+    fn new(chunks: Chunks<R>) -> Self {
+        StreamParse {
+            chunks,
+            marker: PhantomData,
+        }
+    }
+}
+
+impl<R, T> Iterator for StreamParse<R, T>
+where
+    R: BufRead,
+    T: FromStr,
+    T::Err: Into<BoxError>,
+{
+    type Item = Result<T, ReaderError>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let parsed = self.chunks.next()?.and_then(|chunk| {
+            chunk.text.parse::<T>().map_err(|err| {
+                let location = Location::of_part(
+                    &chunk.text,
+                    self.chunks.path.as_deref(),
+                    chunk.line,
+                    chunk.column,
+                    chunk.block,
+                );
+                ReaderError::parse::<T>(&chunk.text, err, location)
+            })
+        });
+
+        if parsed.is_err() {
+            self.chunks.finished = true;
+        }
+        Some(parsed)
+    }
+}
+
+#[cfg(test)]
+fn from_text(text: &str) -> StreamReader<std::io::Cursor<&str>> {
+    StreamReader::new(std::io::Cursor::new(text))
+}
+
+#[test]
+fn test_normalize_trailing_lines() {
+    let parts: Vec<usize> = from_text("1,2,3\n").split_line_into(",").collect();
+    assert_eq!(parts, vec![1, 2, 3]);
+
+    let lines: Vec<String> = from_text("1\n\n2\r\n \n\t\n").lines().collect();
+    assert_eq!(lines, vec!["1", "", "2"]);
+
+    let parts: Vec<String> = from_text("1\n2\n\n\n").split_line("\n").collect();
+    assert_eq!(parts, vec!["1", "2"]);
+}
+
+#[test]
+fn test_normalize_bom_and_newlines() {
+    let lines: Vec<usize> = from_text("\u{feff}1\r\n2").lines_as().collect();
+    assert_eq!(lines, vec![1, 2]);
+
+    let blocks: Vec<String> = from_text("a\r\nb\r\n\r\nc\r\n")
+        .split_line("\n\n")
+        .collect();
+    assert_eq!(blocks, vec!["a\nb", "c"]);
+}
+
+#[test]
+fn test_normalize_blank_lines() {
+    let blocks: Vec<String> = from_text("1\n2\n  \n3\n").split_on_empty_line().collect();
+    assert_eq!(blocks, vec!["1\n2", "3"]);
+
+    let blocks: Vec<String> = from_text("1\n \t\n2").split_line("\n\n").collect();
+    assert_eq!(blocks, vec!["1", "2"]);
+}
+
+#[test]
+fn test_normalize_none() {
+    let parts: Vec<String> =
+        StreamReader::with_normalize(std::io::Cursor::new("1,2\r\n\n"), Normalize::none())
+            .split_line(",")
+            .collect();
+    assert_eq!(parts, vec!["1", "2\r\n\n"]);
+}
+
+#[test]
+fn test_normalize_strict() {
+    let strict =
+        |text| StreamReader::with_normalize(std::io::Cursor::new(text), Normalize::strict());
+
+    let lines: Vec<String> = strict("1\n2\n").lines().collect();
+    assert_eq!(lines, vec!["1", "2"]);
+
+    let err = strict("1\n2\r\n3")
+        .try_lines()
+        .find_map(Result::err)
+        .unwrap();
+    assert!(matches!(err, ReaderError::Malformed { .. }));
+    assert_eq!(
+        err.location()
+            .map(|location| (location.line, location.column)),
+        Some((2, 2))
+    );
+
+    let err = strict("1\n2\n\n\n")
+        .try_lines()
+        .find_map(Result::err)
+        .unwrap();
+    assert_eq!(err.location().map(|location| location.line), Some(3));
+}
+
+#[test]
+fn test_lines() {
+    let lines: Vec<String> = from_text("a\nbc\n\nd").lines().collect();
+    assert_eq!(lines, vec!["a", "bc", "", "d"]);
+
+    let numbers: Vec<usize> = from_text("1\n2\n3\n").lines_as().collect();
+    assert_eq!(numbers, vec![1, 2, 3]);
+}
+
+#[test]
+fn test_blocks() {
+    let blocks: Vec<String> = from_text("\n1\n2\n\n\n\n3\n\n4\n")
+        .split_on_empty_line()
+        .collect();
+    assert_eq!(blocks, vec!["1\n2", "3", "4"]);
+}
+
+#[test]
+fn test_multi_byte_pattern() {
+    let parts: Vec<String> = from_text("a->b->->c").split_line("->").collect();
+    assert_eq!(parts, vec!["a", "b", "", "c"]);
+
+    let parts: Vec<String> = from_text("ä, ö, å").split_line(", ").collect();
+    assert_eq!(parts, vec!["ä", "ö", "å"]);
+}
+
+#[test]
+fn test_parse_error_location() {
+    let err = from_text("1,2,x,4")
+        .try_split_line_into::<usize>(",")
+        .find_map(Result::err)
+        .unwrap();
+    let location = err.location().unwrap();
+    assert_eq!((location.line, location.column), (1, 5));
+    assert!(err.to_string().starts_with("Unable to parse \"x\""));
+
+    let err = from_text("12\n34\n5x")
+        .try_split_line_into::<usize>("\n")
+        .find_map(Result::err)
+        .unwrap();
+    let location = err.location().unwrap();
+    assert_eq!((location.line, location.column), (3, 1));
+
+    let err = from_text("1\n\n\nx\n4")
+        .try_split_on_empty_line_into::<usize>()
+        .find_map(Result::err)
+        .unwrap();
+    let location = err.location().unwrap();
+    assert_eq!(
+        (location.line, location.end_line, location.block),
+        (4, 5, Some(2))
+    );
+}
+
+#[test]
+fn test_stops_after_first_error() {
+    let parsed: Vec<Result<usize, ReaderError>> = from_text("1\nx\ny\n4").try_lines_as().collect();
+    assert_eq!(parsed.len(), 2);
+    assert_eq!(*parsed[0].as_ref().unwrap(), 1);
+    assert!(parsed[1].is_err());
+}
+
+#[test]
+fn test_blocks_match_reader() {
+    for text in ["a\n\n\nb", "\n\na\nb\n\n\n\nc\n", "a\n\nb\n\n"] {
+        let reader: crate::Reader = text.parse().unwrap();
+        let blocks: Vec<String> = from_text(text).split_on_empty_line().collect();
+
+        assert_eq!(blocks, reader.clone().split_on_empty_line(), "{:?}", text);
+        assert_eq!(
+            blocks,
+            reader
+                .split_on_empty_line_iter_into::<String>()
+                .collect::<Vec<_>>(),
+            "{:?}",
+            text
+        );
+    }
+}
@@ -0,0 +1,192 @@
+use serde::{de, Deserialize, Deserializer, Serialize, Serializer};
+use std::fmt;
+
+/// Answer to one part of a puzzle.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum Answer {
+    Number(i128),
+    /// Text answers on a single line, such as crate labels.
+    Text(String),
+    /// Rows of a picture drawn over several lines, such as the letters on a screen.
+    Grid(Vec<String>),
+}
+
+impl Answer {
+    /// Whether the answer spans several lines and is best printed on its own.
+    pub fn is_multiline(&self) -> bool {
+        matches!(self, Answer::Grid(_))
+    }
+}
+
+impl fmt::Display for Answer {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Answer::Number(number) => write!(f, "{}", number),
+            Answer::Text(text) => write!(f, "{}", text),
+            Answer::Grid(rows) => write!(f, "{}", rows.join("\n")),
+        }
+    }
+}
+
+macro_rules! answer_from_number {
+    ($($ty:ty),+) => {
+        $(
+            impl From<$ty> for Answer {
+                fn from(number: $ty) -> Self {
+                    Answer::Number(number as i128)
+                }
+            }
+        )+
+    };
+}
+
+answer_from_number!(i8, i16, i32, i64, isize, u8, u16, u32, u64, usize);
+
+/// Text over several lines becomes a grid of its rows.
+impl From<String> for Answer {
+    fn from(text: String) -> Self {
+        if text.contains('\n') {
+            Answer::Grid(text.lines().map(|row| row.to_string()).collect())
+        } else {
+            Answer::Text(text)
+        }
+    }
+}
+
+impl From<&str> for Answer {
+    fn from(text: &str) -> Self {
+        Answer::from(text.to_string())
+    }
+}
+
+/// Numbers are stored as integers where the format allows it, and as text otherwise.
+impl Serialize for Answer {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        match self {
+            Answer::Number(number) => match i64::try_from(*number) {
+                Ok(number) => serializer.serialize_i64(number),
+                Err(_) => serializer.serialize_str(&number.to_string()),
+            },
+            Answer::Text(text) => serializer.serialize_str(text),
+            Answer::Grid(rows) => serializer.serialize_str(&rows.join("\n")),
+        }
+    }
+}
+
+impl<'de> Deserialize<'de> for Answer {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        deserializer.deserialize_any(AnswerVisitor)
+    }
+}
+
+struct AnswerVisitor;
+
+impl<'de> de::Visitor<'de> for AnswerVisitor {
+    type Value = Answer;
+
+    fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "an integer or a string")
+    }
+
+    fn visit_i64<E: de::Error>(self, number: i64) -> Result<Answer, E> {
+        Ok(Answer::Number(number as i128))
+    }
+
+    fn visit_u64<E: de::Error>(self, number: u64) -> Result<Answer, E> {
+        Ok(Answer::Number(number as i128))
+    }
+
+    fn visit_i128<E: de::Error>(self, number: i128) -> Result<Answer, E> {
+        Ok(Answer::Number(number))
+    }
+
+    /// Strings are text, except for the numbers beyond `i64` that `serialize`
+    /// stores as text.
+    fn visit_str<E: de::Error>(self, text: &str) -> Result<Answer, E> {
+        match number(text) {
+            Some(number) if i64::try_from(number).is_err() => Ok(Answer::Number(number)),
+            _ => Ok(Answer::from(text)),
+        }
+    }
+}
+
+/// Parse an answer given on the command line. Integers become numbers, unless
+/// they are written differently than the number is displayed, so that `0042`
+/// stays text like it does in `answers.toml`.
+impl std::str::FromStr for Answer {
+    type Err = std::convert::Infallible;
+
+    fn from_str(text: &str) -> Result<Self, Self::Err> {
+        Ok(match number(text) {
+            Some(number) => Answer::Number(number),
+            None => Answer::from(text),
+        })
+    }
+}
+
+/// The integer in the text, if the text is exactly how the integer displays.
+fn number(text: &str) -> Option<i128> {
+    text.parse::<i128>()
+        .ok()
+        .filter(|number| number.to_string() == text)
+}
+
+#[test]
+fn test_deserialize() {
+    #[derive(Deserialize)]
+    struct Answers {
+        number: Answer,
+        text: Answer,
+        digits: Answer,
+        large: Answer,
+        grid: Answer,
+    }
+
+    let answers: Answers = toml::from_str(
+        "number = 42\ntext = \"CMZ\"\ndigits = \"0042\"\nlarge = \"170141183460469231731687303715884105727\"\ngrid = \"\"\"\n#.\n.#\"\"\"",
+    )
+    .unwrap();
+    assert_eq!(answers.number, Answer::Number(42));
+    assert_eq!(answers.text, Answer::Text("CMZ".to_string()));
+    assert_eq!(answers.digits, Answer::Text("0042".to_string()));
+    assert_eq!(answers.large, Answer::Number(i128::MAX));
+    assert_eq!(
+        answers.grid,
+        Answer::Grid(vec!["#.".to_string(), ".#".to_string()])
+    );
+}
+
+#[test]
+fn test_parse_round_trip() {
+    #[derive(Serialize, Deserialize)]
+    struct Saved {
+        answer: Answer,
+    }
+
+    let texts = [
+        "42",
+        "-7",
+        "0042",
+        "+7",
+        "-0",
+        "CMZ",
+        "170141183460469231731687303715884105727",
+    ];
+    for text in texts {
+        let answer: Answer = text.parse().unwrap();
+        assert_eq!(answer.to_string(), text);
+
+        let saved = toml::to_string(&Saved {
+            answer: answer.clone(),
+        })
+        .unwrap();
+        let loaded: Saved = toml::from_str(&saved).unwrap();
+        assert_eq!(loaded.answer, answer, "{}", text);
+    }
+
+    assert_eq!("42".parse::<Answer>().unwrap(), Answer::Number(42));
+    assert_eq!(
+        "0042".parse::<Answer>().unwrap(),
+        Answer::Text("0042".to_string())
+    );
+}
@@ -0,0 +1,166 @@
+use crate::{Answer, InputKind, Part};
+use serde::{Deserialize, Serialize};
+use std::{fmt, fs, io, path::Path};
+use toml_edit::{ser::ValueSerializer, DocumentMut, Item, Value};
+
+/// Confirmed answers of a day, kept in `answers.toml` in the day's directory.
+/// ## Example
+/// ```toml
+/// [example]
+/// part_one = 24000
+/// part_two = 45000
+///
+/// [real]
+/// part_one = 71506
+/// ```
+#[derive(Debug, Clone, Default, PartialEq, Eq, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct Answers {
+    #[serde(default)]
+    example: Parts,
+    #[serde(default)]
+    real: Parts,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Eq, Deserialize)]
+#[serde(deny_unknown_fields)]
+struct Parts {
+    #[serde(default)]
+    part_one: Option<Answer>,
+    #[serde(default)]
+    part_two: Option<Answer>,
+}
+
+impl Answers {
+    pub const FILE: &'static str = "answers.toml";
+
+    /// Read the answers from the given directory. A missing file has no answers.
+    pub fn load(dir: &Path) -> Result<Answers, String> {
+        let path = dir.join(Answers::FILE);
+        match fs::read_to_string(&path) {
+            Ok(text) => toml::from_str(&text)
+                .map_err(|err| format!("Invalid answers in {}: {}", path.display(), err)),
+            Err(err) if err.kind() == io::ErrorKind::NotFound => Ok(Answers::default()),
+            Err(err) => Err(format!("Unable to read {}: {}", path.display(), err)),
+        }
+    }
+
+    /// Write the answers to the given directory. Only the answers that differ
+    /// from the file are written, so comments and formatting are kept.
+    pub fn save(&self, dir: &Path) -> Result<(), String> {
+        let path = dir.join(Answers::FILE);
+        let text = match fs::read_to_string(&path) {
+            Ok(text) => text,
+            Err(err) if err.kind() == io::ErrorKind::NotFound => String::new(),
+            Err(err) => return Err(format!("Unable to read {}: {}", path.display(), err)),
+        };
+        let saved: Answers = toml::from_str(&text)
+            .map_err(|err| format!("Invalid answers in {}: {}", path.display(), err))?;
+        let mut document: DocumentMut = text
+            .parse()
+            .map_err(|err| format!("Invalid answers in {}: {}", path.display(), err))?;
+
+        for (kind, table) in [(InputKind::Example, "example"), (InputKind::Real, "real")] {
+            for (part, key) in [(Part::One, "part_one"), (Part::Two, "part_two")] {
+                match self.get(kind, part) {
+                    Some(answer) if saved.get(kind, part) != Some(answer) => {
+                        document
+                            .entry(table)
+                            .or_insert_with(toml_edit::table)
+                            .as_table_like_mut()
+                            .ok_or_else(|| {
+                                format!("[{}] in {} is not a table.", table, path.display())
+                            })?
+                            .insert(key, Item::Value(value(answer)?));
+                    }
+                    _ => (),
+                }
+            }
+        }
+
+        fs::write(&path, document.to_string())
+            .map_err(|err| format!("Unable to write {}: {}", path.display(), err))
+    }
+
+    pub fn get(&self, kind: InputKind, part: Part) -> Option<&Answer> {
+        let parts = match kind {
+            InputKind::Example => &self.example,
+            InputKind::Real => &self.real,
+        };
+        match part {
+            Part::One => parts.part_one.as_ref(),
+            Part::Two => parts.part_two.as_ref(),
+        }
+    }
+
+    pub fn set(&mut self, kind: InputKind, part: Part, answer: Answer) {
+        let parts = match kind {
+            InputKind::Example => &mut self.example,
+            InputKind::Real => &mut self.real,
+        };
+        match part {
+            Part::One => parts.part_one = Some(answer),
+            Part::Two => parts.part_two = Some(answer),
+        }
+    }
+
+    /// Compare an answer with the confirmed one.
+    pub fn check(&self, kind: InputKind, part: Part, answer: &Answer) -> Verdict {
+        match self.get(kind, part) {
+            Some(expected) if expected == answer => Verdict::Correct,
+            Some(expected) => Verdict::Wrong(expected.clone()),
+            None => Verdict::Unknown,
+        }
+    }
+}
+
+/// An answer as a TOML value, stored the way `Answer` serializes it.
+fn value(answer: &Answer) -> Result<Value, String> {
+    answer
+        .serialize(ValueSerializer::new())
+        .map_err(|err| err.to_string())
+}
+
+/// Result of comparing an answer with the confirmed one.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Verdict {
+    Correct,
+    /// Holds the confirmed answer.
+    Wrong(Answer),
+    /// No answer has been confirmed for the input.
+    Unknown,
+}
+
+impl fmt::Display for Verdict {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Verdict::Correct => write!(f, "correct"),
+            Verdict::Wrong(expected) if expected.is_multiline() => write!(f, "wrong"),
+            Verdict::Wrong(expected) => write!(f, "wrong, expected {}", expected),
+            Verdict::Unknown => write!(f, "unknown"),
+        }
+    }
+}
+
+#[test]
+fn test_save_keeps_comments() {
+    let dir = std::env::temp_dir().join(format!("solution-answers-{}", std::process::id()));
+    fs::create_dir_all(&dir).unwrap();
+    fs::write(
+        dir.join(Answers::FILE),
+        "# Answers of day 10\n[example]\npart_one = 13140 # from the puzzle\n\n[real]\n",
+    )
+    .unwrap();
+
+    let mut answers = Answers::load(&dir).unwrap();
+    answers.set(InputKind::Real, Part::One, 12460.into());
+    answers.set(InputKind::Real, Part::Two, "#.\n.#".into());
+    answers.save(&dir).unwrap();
+
+    assert_eq!(
+        fs::read_to_string(dir.join(Answers::FILE)).unwrap(),
+        "# Answers of day 10\n[example]\npart_one = 13140 # from the puzzle\n\n[real]\npart_one = 12460\npart_two = \"\"\"\n#.\n.#\"\"\"\n"
+    );
+    assert_eq!(Answers::load(&dir).unwrap(), answers);
+    fs::remove_dir_all(&dir).unwrap();
+}
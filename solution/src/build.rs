@@ -0,0 +1,49 @@
+//! Support for the build scripts of the days.
+
+use crate::{input_path, missing, Answers, InputKind, Part};
+use std::{env, path::Path};
+
+/// Set the cfgs that `answer_tests!` uses to ignore the tests that have nothing
+/// to check: `aoc_missing_input` when `input.txt` is missing or empty, and
+/// `aoc_unknown_<test>` for each test whose answer is not in `answers.toml`.
+///
+/// Call it from the `build.rs` of a day. Cargo runs the build script again
+/// whenever `answers.toml`, `input.txt` or `AOC_INPUT_DIR` change, and on every
+/// build while `input.txt` is missing.
+/// ## Example
+/// ```rs
+/// fn main() {
+///     solution::build::answer_test_cfgs();
+/// }
+/// ```
+pub fn answer_test_cfgs() {
+    let dir = env::var("CARGO_MANIFEST_DIR").expect("Run from a build script");
+    let input = input_path(&dir, InputKind::Real);
+    let dir = Path::new(&dir);
+
+    let tests = [
+        ("part_one_example", Part::One, InputKind::Example),
+        ("part_one", Part::One, InputKind::Real),
+        ("part_two_example", Part::Two, InputKind::Example),
+        ("part_two", Part::Two, InputKind::Real),
+    ];
+
+    println!("cargo:rerun-if-changed={}", Answers::FILE);
+    println!("cargo:rerun-if-changed={}", input.display());
+    println!("cargo:rerun-if-env-changed={}", reader::INPUT_DIR_VAR);
+    println!("cargo:rustc-check-cfg=cfg(aoc_missing_input)");
+    for (test, _, _) in tests {
+        println!("cargo:rustc-check-cfg=cfg(aoc_unknown_{})", test);
+    }
+
+    if missing(&input) {
+        println!("cargo:rustc-cfg=aoc_missing_input");
+    }
+
+    let answers = Answers::load(dir).unwrap_or_else(|err| panic!("{}", err));
+    for (test, part, kind) in tests {
+        if answers.get(kind, part).is_none() {
+            println!("cargo:rustc-cfg=aoc_unknown_{}", test);
+        }
+    }
+}
@@ -0,0 +1,360 @@
+mod answer;
+mod answers;
+pub mod build;
+
+pub use answer::Answer;
+pub use answers::{Answers, Verdict};
+
+use reader::Reader;
+use std::{
+    any::Any,
+    fmt, fs,
+    io::{self, Write},
+    path::{Path, PathBuf},
+};
+
+/// Which input a part is solved for. Some puzzles use different parameters
+/// for the example, like the row scanned on day 15.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum InputKind {
+    /// `input_example.txt` from the puzzle description.
+    Example,
+    /// The personal puzzle input, `input.txt`.
+    Real,
+}
+
+impl InputKind {
+    /// Name of the input file in the day's directory.
+    pub fn file_name(self) -> &'static str {
+        match self {
+            InputKind::Example => "input_example.txt",
+            InputKind::Real => "input.txt",
+        }
+    }
+}
+
+impl fmt::Display for InputKind {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            InputKind::Example => write!(f, "example"),
+            InputKind::Real => write!(f, "real"),
+        }
+    }
+}
+
+/// Part of a puzzle.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Part {
+    One,
+    Two,
+}
+
+impl Part {
+    pub const BOTH: [Part; 2] = [Part::One, Part::Two];
+}
+
+impl fmt::Display for Part {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Part::One => write!(f, "1"),
+            Part::Two => write!(f, "2"),
+        }
+    }
+}
+
+/// Solution to the puzzle of a single day. The input is parsed once, and both
+/// parts are solved from the parsed input so that parsing can be timed apart.
+/// ## Example
+/// ```rs
+/// pub struct Day1;
+///
+/// impl Solution for Day1 {
+///     const DAY: u8 = 1;
+///     const TITLE: &'static str = "Calorie Counting";
+///     const DIR: &'static str = env!("CARGO_MANIFEST_DIR");
+///
+///     type Input = Vec<usize>;
+///     type PartOne = usize;
+///     type PartTwo = usize;
+///
+///     fn parse(input: Reader) -> Vec<usize> {
+///         parse(input)
+///     }
+///
+///     fn part_one(input: &Vec<usize>) -> usize {
+///         part_one(input)
+///     }
+///
+///     fn part_two(input: &Vec<usize>) -> usize {
+///         part_two(input)
+///     }
+/// }
+/// ```
+pub trait Solution {
+    const DAY: u8;
+    const TITLE: &'static str;
+    /// Directory of the day's crate that holds its inputs. Set it to
+    /// `env!("CARGO_MANIFEST_DIR")` so inputs are found from any working directory.
+    const DIR: &'static str;
+
+    /// The input parsed for both parts.
+    type Input: 'static;
+    type PartOne: Into<Answer>;
+    type PartTwo: Into<Answer>;
+
+    fn parse(input: Reader) -> Self::Input;
+
+    fn part_one(input: &Self::Input) -> Self::PartOne;
+
+    fn part_two(input: &Self::Input) -> Self::PartTwo;
+
+    /// Solve part one for the given kind of input. Override when the example
+    /// needs different parameters than the real input.
+    fn part_one_for(input: &Self::Input, _kind: InputKind) -> Self::PartOne {
+        Self::part_one(input)
+    }
+
+    /// Solve part two for the given kind of input, see `part_one_for`.
+    fn part_two_for(input: &Self::Input, _kind: InputKind) -> Self::PartTwo {
+        Self::part_two(input)
+    }
+}
+
+/// Input parsed by `Day::parse`, to be solved by the same day.
+pub struct Parsed(Box<dyn Any>);
+
+/// Type erased `Solution`, so that every day can be registered in one list.
+#[derive(Debug, Clone, Copy)]
+pub struct Day {
+    pub day: u8,
+    pub title: &'static str,
+    pub dir: &'static str,
+    parse: fn(Reader) -> Parsed,
+    part_one: fn(&Parsed, InputKind) -> Answer,
+    part_two: fn(&Parsed, InputKind) -> Answer,
+}
+
+impl Day {
+    pub fn of<S: Solution>() -> Day {
+        Day {
+            day: S::DAY,
+            title: S::TITLE,
+            dir: S::DIR,
+            parse: |input| Parsed(Box::new(S::parse(input))),
+            part_one: |input, kind| S::part_one_for(input.get::<S>(), kind).into(),
+            part_two: |input, kind| S::part_two_for(input.get::<S>(), kind).into(),
+        }
+    }
+
+    /// Parse the input for both parts.
+    pub fn parse(&self, input: Reader) -> Parsed {
+        (self.parse)(input)
+    }
+
+    /// Solve the given part of input parsed by this day.
+    pub fn solve_parsed(&self, part: Part, input: &Parsed, kind: InputKind) -> Answer {
+        match part {
+            Part::One => (self.part_one)(input, kind),
+            Part::Two => (self.part_two)(input, kind),
+        }
+    }
+
+    /// Parse the input and solve the given part.
+    pub fn solve(&self, part: Part, input: Reader, kind: InputKind) -> Answer {
+        self.solve_parsed(part, &self.parse(input), kind)
+    }
+
+    /// Path of the given input of the day, see `input_path`.
+    pub fn input_path(&self, kind: InputKind) -> PathBuf {
+        input_path(self.dir, kind)
+    }
+
+    /// Confirmed answers from the day's `answers.toml`.
+    pub fn answers(&self) -> Result<Answers, String> {
+        Answers::load(Path::new(self.dir))
+    }
+}
+
+impl Parsed {
+    fn get<S: Solution>(&self) -> &S::Input {
+        self.0
+            .downcast_ref()
+            .unwrap_or_else(|| panic!("Input was not parsed by day {}", S::DAY))
+    }
+}
+
+/// Path of the given input of the day in `dir`, found with `reader::resolve` so
+/// that `$AOC_INPUT_DIR/day-N` is searched before the day's directory. When the
+/// input is found nowhere, its path in the day's directory.
+pub fn input_path(dir: &str, kind: InputKind) -> PathBuf {
+    reader::resolve(kind.file_name(), Some(dir))
+        .unwrap_or_else(|_| PathBuf::from(dir).join(kind.file_name()))
+}
+
+/// Entry point of a day's own binary. Solves both parts for the input piped
+/// into standard input when given `-` as an argument, or `input.txt` from the
+/// day's directory. Answers for `input.txt` are checked against the confirmed ones.
+pub fn main<S: Solution>() {
+    let day = Day::of::<S>();
+    let stdin = reader::piped_stdin().unwrap_or_else(|err| panic!("{}", err));
+    let (input, answers) = match stdin {
+        Some(input) => (input, None),
+        None => (
+            reader::open(&day.input_path(InputKind::Real).to_string_lossy()),
+            Some(day.answers().unwrap_or_else(|err| panic!("{}", err))),
+        ),
+    };
+
+    println!("Day {}: {}", day.day, day.title);
+    let input = day.parse(input);
+    for part in Part::BOTH {
+        let answer = day.solve_parsed(part, &input, InputKind::Real);
+        let verdict = answers
+            .as_ref()
+            .map(|answers| answers.check(InputKind::Real, part, &answer));
+        print_answer(part, &answer, verdict.as_ref());
+    }
+}
+
+/// Print an answer indented below its day, multi-line answers start on their own line.
+/// The verdict is left out for inputs that have no confirmed answers.
+pub fn print_answer(part: Part, answer: &Answer, verdict: Option<&Verdict>) {
+    let verdict = verdict
+        .map(|verdict| format!(" ({})", verdict))
+        .unwrap_or_default();
+
+    if answer.is_multiline() {
+        println!("\tPart {}{}:\n{}", part, verdict, answer);
+    } else {
+        println!("\tPart {}: {}{}", part, answer, verdict);
+    }
+}
+
+/// Solve a part and compare it with the answer confirmed in `answers.toml`,
+/// used by the tests generated with `answer_tests!`. Parts without a confirmed
+/// answer are skipped, and so is the real input when `input.txt` is missing or
+/// empty since puzzle inputs are personal and not shared with the repository.
+pub fn check_answer<S: Solution>(part: Part, kind: InputKind) {
+    let day = Day::of::<S>();
+    let answers = day.answers().unwrap_or_else(|err| panic!("{}", err));
+
+    let Some(expected) = answers.get(kind, part) else {
+        skip(format_args!(
+            "Day {} part {} has no confirmed answer for the {} input, skipping.",
+            day.day, part, kind
+        ));
+        return;
+    };
+
+    let path = day.input_path(kind);
+    if kind == InputKind::Real && missing(&path) {
+        skip(format_args!(
+            "Day {} part {} skipped, {} is missing or empty. Download your input from https://adventofcode.com/2022/day/{}/input.",
+            day.day,
+            part,
+            path.display(),
+            day.day
+        ));
+        return;
+    }
+
+    let input = reader::open(&path.to_string_lossy());
+    assert_eq!(
+        &day.solve(part, input, kind),
+        expected,
+        "Day {} part {} for the {} input",
+        day.day,
+        part,
+        kind
+    );
+}
+
+/// Whether an input file is missing or contains only whitespace, as it has
+/// not been added to the day yet.
+pub fn missing(path: &Path) -> bool {
+    fs::read_to_string(path).map_or(true, |text| text.trim().is_empty())
+}
+
+/// Tell why a test was skipped. The test harness captures `eprintln!` of passing
+/// tests, so the message is written to standard error directly.
+fn skip(reason: fmt::Arguments) {
+    let _ = writeln!(io::stderr(), "{}", reason);
+}
+
+/// Tests checking both parts for the example and real inputs against `answers.toml`.
+///
+/// Tests without a confirmed answer, and the real input tests when `input.txt`
+/// is missing or empty, are marked `#[ignore]` from the cfgs set by
+/// `build::answer_test_cfgs` in the day's build script. Without the build
+/// script these tests are still skipped by `check_answer`, but they show up as
+/// passed.
+/// ## Example
+/// ```rs
+/// solution::answer_tests!(Day1);
+/// ```
+#[macro_export]
+macro_rules! answer_tests {
+    ($solution:ty) => {
+        #[test]
+        #[cfg_attr(aoc_unknown_part_one_example, ignore = "no confirmed answer")]
+        fn test_part_one_example() {
+            $crate::check_answer::<$solution>($crate::Part::One, $crate::InputKind::Example);
+        }
+
+        #[test]
+        #[cfg_attr(aoc_missing_input, ignore = "input.txt missing")]
+        #[cfg_attr(
+            all(aoc_unknown_part_one, not(aoc_missing_input)),
+            ignore = "no confirmed answer"
+        )]
+        fn test_part_one() {
+            $crate::check_answer::<$solution>($crate::Part::One, $crate::InputKind::Real);
+        }
+
+        #[test]
+        #[cfg_attr(aoc_unknown_part_two_example, ignore = "no confirmed answer")]
+        fn test_part_two_example() {
+            $crate::check_answer::<$solution>($crate::Part::Two, $crate::InputKind::Example);
+        }
+
+        #[test]
+        #[cfg_attr(aoc_missing_input, ignore = "input.txt missing")]
+        #[cfg_attr(
+            all(aoc_unknown_part_two, not(aoc_missing_input)),
+            ignore = "no confirmed answer"
+        )]
+        fn test_part_two() {
+            $crate::check_answer::<$solution>($crate::Part::Two, $crate::InputKind::Real);
+        }
+    };
+}
+
+#[test]
+fn test_input_path() {
+    let root = std::env::temp_dir().join(format!("solution-inputs-{}", std::process::id()));
+    let day = root.join("day-9");
+    let inputs = root.join("inputs");
+    fs::create_dir_all(&day).unwrap();
+    fs::create_dir_all(inputs.join("day-9")).unwrap();
+    let dir = day.to_str().unwrap();
+    std::env::set_var(reader::INPUT_DIR_VAR, &inputs);
+
+    // Found nowhere, so the path in the day's directory.
+    assert_eq!(input_path(dir, InputKind::Real), day.join("input.txt"));
+
+    fs::write(day.join("input.txt"), "1").unwrap();
+    assert_eq!(input_path(dir, InputKind::Real), day.join("input.txt"));
+
+    fs::write(inputs.join("day-9/input.txt"), "2").unwrap();
+    assert_eq!(
+        input_path(dir, InputKind::Real),
+        inputs.join("day-9/input.txt")
+    );
+    assert_eq!(
+        input_path(dir, InputKind::Example),
+        day.join("input_example.txt")
+    );
+
+    std::env::remove_var(reader::INPUT_DIR_VAR);
+    fs::remove_dir_all(&root).unwrap();
+}